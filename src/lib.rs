@@ -29,6 +29,8 @@
 //! | Feature | Description |
 //! | ------- | ----------- |
 //! | "serde" | Enables serialization and deserialization of data using [serde](https://crates.io/crates/serde). |
+//! | "either" | Enables conversions to and from [either](https://crates.io/crates/either) values. |
+//! | "approx" | Enables approximate equality comparisons using [approx](https://crates.io/crates/approx). |
 //!
 //! By default, there are no features enabled.
 //!
@@ -39,7 +41,6 @@
 #![warn(anonymous_parameters)]
 #![warn(bad_style)]
 #![warn(bare_trait_objects)]
-#![warn(const_err)]
 #![warn(dead_code)]
 #![warn(elided_lifetimes_in_paths)]
 #![warn(improper_ctypes)]
@@ -53,7 +54,6 @@
 #![warn(overflowing_literals)]
 #![warn(path_statements)]
 #![warn(patterns_in_fns_without_body)]
-#![warn(private_in_public)]
 #![warn(rust_2018_idioms)]
 #![warn(trivial_casts)]
 #![warn(trivial_numeric_casts)]
@@ -71,16 +71,25 @@
 // External library imports.
 #[cfg(feature = "serde")]
 use serde::{ Serialize, Deserialize };
+#[cfg(feature = "either")]
+use either::Either;
+#[cfg(feature = "approx")]
+use approx::{ AbsDiffEq, RelativeEq };
 
 
 ////////////////////////////////////////////////////////////////////////////////
 // Few
 ////////////////////////////////////////////////////////////////////////////////
 /// A type which may contain zero, one, or two of a value.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+///
+/// The values of a `Two` are laid out contiguously, with the same layout as
+/// `[T; 2]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[repr(u8)]
 pub enum Few<T> {
     /// No value present.
+    #[default]
     Zero,
     /// One value present.
     One(T),
@@ -91,26 +100,17 @@ pub enum Few<T> {
 impl<T> Few<T> {
     /// Returns true if the `Few` is a `Zero` value.
     pub fn is_zero(&self) -> bool {
-        match self {
-            Few::Zero => true,
-            _         => false,
-        }
+        matches!(self, Few::Zero)
     }
 
     /// Returns true if the `Few` is a `One` value.
     pub fn is_one(&self) -> bool {
-        match self {
-            Few::One(_) => true,
-            _           => false,
-        }
+        matches!(self, Few::One(_))
     }
 
     /// Returns true if the `Few` is a `Two` value.
     pub fn is_two(&self) -> bool {
-        match self {
-            Few::Two(_, _) => true,
-            _              => false,
-        }
+        matches!(self, Few::Two(_, _))
     }
 
     /// Returns true if the `Few` is a `One` or `Two` value containing the given
@@ -135,106 +135,2202 @@ impl<T> Few<T> {
             Few::Two(a, b) => Few::Two((f)(a), (f)(b)),
         }
     }
-}
 
-impl<T> Iterator for Few<T> {
-    type Item = T;
+    /// Returns true if the `Few`, interpreted as a range, is well-formed. A
+    /// `Two` is well-formed if its first value is not greater than its second.
+    pub fn check_range_invariant(&self) -> bool
+        where T: Ord
+    {
+        match self {
+            Few::Two(a, b) => a <= b,
+            _              => true,
+        }
+    }
 
-    fn next(&mut self) -> Option<T> {
-        let mut res = None;
-        replace_with(self, |curr|
-            match curr {
-                Few::Zero      => { res = None;    Few::Zero },
-                Few::One(v)    => { res = Some(v); Few::Zero },
-                Few::Two(a, b) => { res = Some(a); Few::One(b) },
+    /// Panics in debug builds if the `Few`, interpreted as a range, is not
+    /// well-formed. Does nothing in release builds.
+    #[track_caller]
+    pub fn debug_assert_range(&self)
+        where T: Ord + std::fmt::Debug
+    {
+        debug_assert!(self.check_range_invariant(),
+            "malformed `Few` range: {:?}", self);
+    }
+
+    /// Maps a `Few<T>` to `Few<U>` by applying a function to each contained
+    /// value along with a reference to the other value, if any. The first value
+    /// of a `Two` is cloned so that the second value's mapping sees it
+    /// unchanged.
+    pub fn map_with_neighbor<U, F>(self, mut f: F) -> Few<U>
+        where
+            T: Clone,
+            F: FnMut(T, Option<&T>) -> U,
+    {
+        match self {
+            Few::Zero      => Few::Zero,
+            Few::One(v)    => Few::One((f)(v, None)),
+            Few::Two(a, b) => {
+                let first = a.clone();
+                let a = (f)(a, Some(&b));
+                let b = (f)(b, Some(&first));
+                Few::Two(a, b)
+            },
+        }
+    }
+
+    /// Returns a raw pointer to the first contained value. The pointer is valid
+    /// for reads of `self.len()` values, and is dangling for a `Zero`.
+    pub fn as_ptr(&self) -> *const T {
+        let base: *const Few<T> = self;
+        let first: *const T = match self {
+            Few::Zero      => return std::ptr::NonNull::dangling().as_ptr(),
+            Few::One(v)    => v,
+            Few::Two(a, _) => a,
+        };
+        // The pointer is derived from `self` rather than from a reference to
+        // the first value, so that it may also be used to access the second.
+        let offset = first as usize - base as usize;
+        base.cast::<u8>().wrapping_add(offset).cast()
+    }
+
+    /// Returns a raw mutable pointer to the first contained value. The pointer
+    /// is valid for reads and writes of `self.len()` values, and is dangling
+    /// for a `Zero`.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        let first: *mut T = match self {
+            Few::Zero      => return std::ptr::NonNull::dangling().as_ptr(),
+            Few::One(v)    => v,
+            Few::Two(a, _) => a,
+        };
+        // The pointer is derived from `self` once the borrow of the first value
+        // has ended, so that it may also be used to access the second.
+        let base: *mut Few<T> = self;
+        let offset = first as usize - base as usize;
+        base.cast::<u8>().wrapping_add(offset).cast()
+    }
+
+    /// Applies a function to references to both values of a `Two`, returning
+    /// the result. Returns `None` for `Zero` and `One` values.
+    pub fn combine_ref<R, F>(&self, f: F) -> Option<R>
+        where F: FnOnce(&T, &T) -> R,
+    {
+        match self {
+            Few::Two(a, b) => Some((f)(a, b)),
+            _              => None,
+        }
+    }
+
+    /// Fills any remaining capacity of the `Few` with values pulled from the
+    /// given iterator, returning the number of values added. No more values are
+    /// pulled than are needed, so passing `&mut iter` leaves the rest of the
+    /// iterator untouched.
+    pub fn fill_from<I>(&mut self, iter: I) -> usize
+        where I: IntoIterator<Item=T>,
+    {
+        let mut iter = iter.into_iter();
+        let mut added = 0;
+        while !self.is_two() {
+            let value = match iter.next() {
+                Some(value) => value,
+                None        => break,
+            };
+            let _ = self.fill_one_more(value);
+            added += 1;
+        }
+        added
+    }
+
+    /// Returns the sorted values which are present in exactly one of the two
+    /// `Few`s, treating each as a set of values. Duplicate values are removed.
+    pub fn symmetric_difference(self, other: Few<T>) -> Vec<T>
+        where T: Ord,
+    {
+        let mut left: Vec<T> = self.collect();
+        left.sort();
+        left.dedup();
+        let mut right: Vec<T> = other.collect();
+        right.sort();
+        right.dedup();
+
+        // Each side is deduplicated, so any value present in both will appear
+        // exactly twice in a row once the sides are merged and sorted.
+        left.append(&mut right);
+        left.sort();
+        let mut result = Vec::with_capacity(left.len());
+        let mut values = left.into_iter().peekable();
+        while let Some(v) = values.next() {
+            if values.peek() == Some(&v) {
+                let _ = values.next();
+            } else {
+                result.push(v);
             }
-        );
-        res
+        }
+        result
     }
-}
 
-impl<T> DoubleEndedIterator for Few<T> {
-    fn next_back(&mut self) -> Option<T> {
-        let mut res = None;
-        replace_with(self, |curr|
-            match curr {
-                Few::Zero      => { res = None;    Few::Zero },
-                Few::One(v)    => { res = Some(v); Few::Zero },
-                Few::Two(a, b) => { res = Some(b); Few::One(a) },
+    /// Converts a `Few<T>` to `Few<U>` by converting each contained value with
+    /// `From`.
+    pub fn cast<U>(self) -> Few<U>
+        where U: From<T>,
+    {
+        self.map(U::from)
+    }
+
+    /// Converts a `Few<T>` to `Few<U>` by converting each contained value with
+    /// `TryFrom`, returning the first conversion error encountered.
+    pub fn try_cast<U>(self) -> Result<Few<U>, U::Error>
+        where U: std::convert::TryFrom<T>,
+    {
+        match self {
+            Few::Zero      => Ok(Few::Zero),
+            Few::One(v)    => Ok(Few::One(U::try_from(v)?)),
+            Few::Two(a, b) => {
+                let a = U::try_from(a)?;
+                Ok(Few::Two(a, U::try_from(b)?))
+            },
+        }
+    }
+
+    /// Returns the values of both `Few`s in alternating order, starting with
+    /// the first value of `self`. The result ends as soon as the `Few` whose
+    /// turn it is has no more values, so `Two(1, 3).interleave(One(2))` gives
+    /// `[1, 2, 3]`, while `One(1).interleave(Two(2, 4))` gives `[1, 2]`.
+    pub fn interleave(self, other: Few<T>) -> Vec<T> {
+        let mut result = Vec::with_capacity(4);
+        let (mut next, mut after) = (self, other);
+        while let Some(v) = next.next() {
+            result.push(v);
+            std::mem::swap(&mut next, &mut after);
+        }
+        result
+    }
+
+    /// Returns the bounds on the number of distinct values in the `Few`, such
+    /// that a `Two` holding equal values has a logical size of one.
+    pub fn size_hint_logical(&self) -> (usize, usize)
+        where T: PartialEq,
+    {
+        match self {
+            Few::Zero                => (0, 0),
+            Few::One(_)              => (1, 1),
+            Few::Two(a, b) if a == b => (1, 1),
+            Few::Two(_, _)           => (2, 2),
+        }
+    }
+
+    /// Sorts the values of a `Two` in ascending order.
+    pub fn sort_ascending(&mut self)
+        where T: Ord,
+    {
+        if let Few::Two(a, b) = self {
+            if a > b {
+                std::mem::swap(a, b);
             }
-        );
-        res
+        }
     }
-}
 
-impl<T> ExactSizeIterator for Few<T> {
-    fn len(&self) -> usize {
+    /// Returns the contained values as a `Cow` slice. The values are always
+    /// borrowed and never cloned, as every variant can be viewed as a slice.
+    pub fn as_cow_slice(&self) -> std::borrow::Cow<'_, [T]>
+        where T: Clone,
+    {
+        // SAFETY: `Few` is `repr(u8)`, so the values of a `Two` are laid out
+        // contiguously, and `as_ptr` is valid for `self.len()` values.
+        let slice = unsafe {
+            std::slice::from_raw_parts(self.as_ptr(), self.len())
+        };
+        std::borrow::Cow::Borrowed(slice)
+    }
+
+    /// Folds the second value of a `Two` into the first using the given
+    /// function, collapsing it into a `One`. `Zero` and `One` values are left
+    /// unchanged. The `Default` value of `T` is used as a placeholder for the
+    /// second value while the variant is changed.
+    pub fn reduce_in_place<F>(&mut self, mut f: F)
+        where
+            T: Default,
+            F: FnMut(&mut T, T),
+    {
+        if let Few::Two(a, b) = self {
+            let b = std::mem::take(b);
+            (f)(a, b);
+            if let Few::Two(a, _) = std::mem::take(self) {
+                *self = Few::One(a);
+            }
+        }
+    }
+
+    /// Returns true if both `Few`s are the same variant, regardless of their
+    /// contained values.
+    pub fn same_shape<U>(&self, other: &Few<U>) -> bool {
+        self.len() == other.len()
+    }
+
+    /// Returns references to the lower and upper bounds of a `Two` interpreted
+    /// as a range. The values are ordered so that the lower bound is first,
+    /// even if they are stored in reverse order. Returns `None` for `Zero` and
+    /// `One` values.
+    pub fn as_range(&self) -> Option<(&T, &T)>
+        where T: Ord,
+    {
         match self {
-            Few::Zero      => 0,
-            Few::One(_)    => 1,
-            Few::Two(_, _) => 2,
+            Few::Two(a, b) if a <= b => Some((a, b)),
+            Few::Two(a, b)           => Some((b, a)),
+            _                        => None,
         }
     }
-}
 
-impl<T> std::iter::FusedIterator for Few<T> {}
+    /// Maps a `Few<T>` to `Few<U>` by applying a fallible function to each
+    /// contained value and its index, returning the first error encountered.
+    pub fn try_map_indexed<U, E, F>(self, mut f: F) -> Result<Few<U>, E>
+        where F: FnMut(usize, T) -> Result<U, E>,
+    {
+        match self {
+            Few::Zero      => Ok(Few::Zero),
+            Few::One(v)    => Ok(Few::One((f)(0, v)?)),
+            Few::Two(a, b) => {
+                let a = (f)(0, a)?;
+                Ok(Few::Two(a, (f)(1, b)?))
+            },
+        }
+    }
 
+    /// Returns a reference to the value that would be returned by `next`,
+    /// without advancing the iterator.
+    pub fn peek(&self) -> Option<&T> {
+        match self {
+            Few::Zero      => None,
+            Few::One(v)    => Some(v),
+            Few::Two(a, _) => Some(a),
+        }
+    }
 
-impl<T> Default for Few<T> {
-    fn default() -> Self {
-        Few::Zero
+    /// Returns a reference to the value that would be returned by `next_back`,
+    /// without advancing the iterator.
+    pub fn peek_back(&self) -> Option<&T> {
+        match self {
+            Few::Zero      => None,
+            Few::One(v)    => Some(v),
+            Few::Two(_, b) => Some(b),
+        }
     }
-}
 
-impl<T> From<T> for Few<T> {
-    fn from(value: T) -> Self {
-        Few::One(value)
+    /// Removes the contained values from the `Few`, returning them in an
+    /// iterator. The `Few` is left as a `Zero` as soon as the iterator is
+    /// created, so any values not yielded are dropped along with the iterator,
+    /// or leaked if it is forgotten.
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        Drain {
+            inner: std::mem::take(self),
+            marker: std::marker::PhantomData,
+        }
     }
-}
 
-impl<T> From<(T, T)> for Few<T> {
-    fn from(value: (T, T)) -> Self {
-        Few::Two(value.0, value.1)
+    /// Converts the `Few` into a `NonEmptyFew`, returning `None` if it is a
+    /// `Zero` value.
+    pub fn into_nonempty(self) -> Option<NonEmptyFew<T>> {
+        match self {
+            Few::Zero      => None,
+            Few::One(v)    => Some(NonEmptyFew::One(v)),
+            Few::Two(a, b) => Some(NonEmptyFew::Two(a, b)),
+        }
     }
-}
 
-impl<T> From<Option<T>> for Few<T> {
-    fn from(value: Option<T>) -> Self {
-        match value {
-            None        => Few::Zero,
-            Some(value) => Few::One(value),
+    /// Computes the union of two `Few`s interpreted as ranges, extending the
+    /// given collection with the resulting ranges. A `One` is treated as a
+    /// range containing a single value, and a `Zero` as an empty range.
+    ///
+    /// Overlapping ranges are merged into a single range, while disjoint ranges
+    /// are added separately with the lower range first. Nothing is added if
+    /// both ranges are empty.
+    pub fn merge_into<C>(self, sink: &mut C, other: Few<T>)
+        where
+            T: Ord + Clone,
+            C: Extend<Few<T>>,
+    {
+        let ranges = match (self.min_max(), other.min_max()) {
+            (None, None) => Few::Zero,
+
+            (Some((lo, hi)), None) |
+            (None, Some((lo, hi))) => Few::One(
+                Few::from_bounds(lo.clone(), hi.clone())),
+
+            (Some((a_lo, a_hi)), Some((b_lo, b_hi)))
+                if a_lo <= b_hi && b_lo <= a_hi => Few::One(
+                    Few::from_bounds(
+                        std::cmp::min(a_lo, b_lo).clone(),
+                        std::cmp::max(a_hi, b_hi).clone())),
+
+            (Some((a_lo, a_hi)), Some((b_lo, b_hi))) => {
+                let a = Few::from_bounds(a_lo.clone(), a_hi.clone());
+                let b = Few::from_bounds(b_lo.clone(), b_hi.clone());
+                if a_lo < b_lo { Few::Two(a, b) } else { Few::Two(b, a) }
+            },
+        };
+        sink.extend(ranges);
+    }
+
+    /// Returns references to the minimum and maximum contained values, or
+    /// `None` for a `Zero`. For a `One`, the same value is returned as both the
+    /// minimum and maximum.
+    pub fn min_max(&self) -> Option<(&T, &T)>
+        where T: Ord,
+    {
+        match self {
+            Few::Zero                => None,
+            Few::One(v)              => Some((v, v)),
+            Few::Two(a, b) if a <= b => Some((a, b)),
+            Few::Two(a, b)           => Some((b, a)),
         }
     }
-}
 
-impl<T> From<Option<(T, T)>> for Few<T> {
-    fn from(value: Option<(T, T)>) -> Self {
-        match value {
-            None         => Few::Zero,
-            Some((a, b)) => Few::Two(a, b),
+    /// Constructs a range from its lower and upper bounds, using a `One` if the
+    /// bounds are equal.
+    fn from_bounds(lo: T, hi: T) -> Few<T>
+        where T: PartialEq,
+    {
+        if lo == hi { Few::One(lo) } else { Few::Two(lo, hi) }
+    }
+
+    /// Returns the smallest range containing both `Few`s interpreted as ranges.
+    /// Unlike a union, this always returns a single range, covering any gap
+    /// between disjoint inputs.
+    pub fn envelope(self, other: Few<T>) -> Few<T>
+        where T: Ord,
+    {
+        bounding_few(self.chain(other))
+    }
+
+    /// Extends the `Few` interpreted as a range so that it contains the given
+    /// value. The returned range is always ordered.
+    fn extend_bounds(self, value: T) -> Few<T>
+        where T: Ord,
+    {
+        match self {
+            Few::Zero                   => Few::One(value),
+            Few::One(v) if value < v    => Few::Two(value, v),
+            Few::One(v) if value > v    => Few::Two(v, value),
+            Few::One(v)                 => Few::One(v),
+            Few::Two(a, b) if a > b     => Few::Two(b, a).extend_bounds(value),
+            Few::Two(a, b) if value < a => Few::Two(value, b),
+            Few::Two(a, b) if value > b => Few::Two(a, value),
+            Few::Two(a, b)              => Few::Two(a, b),
         }
     }
-}
 
-impl<T> From<(Option<T>, Option<T>)> for Few<T> {
-    fn from(value: (Option<T>, Option<T>)) -> Self {
-        match (value.0, value.1) {
-            (None,    None)    => Few::Zero,
-            (Some(a), None)    => Few::One(a),
-            (None,    Some(b)) => Few::One(b),
-            (Some(a), Some(b)) => Few::Two(a, b),
+    /// Collects exactly `expected` values from the given iterator, returning an
+    /// error holding any collected values if the iterator is shorter or longer
+    /// than expected. An error is always returned if `expected` is greater than
+    /// two, as a `Few` cannot hold that many values.
+    pub fn from_iter_exact<I>(iter: I, expected: usize)
+        -> Result<Few<T>, LenMismatch<T>>
+        where I: IntoIterator<Item=T>,
+    {
+        let mut iter = iter.into_iter();
+        let mut collected = Few::Zero;
+        let _ = collected.fill_from(iter.by_ref().take(expected));
+        if collected.len() < expected {
+            return Err(LenMismatch { expected, collected, extra: None });
+        }
+        match iter.next() {
+            None        => Ok(collected),
+            Some(extra) => Err(LenMismatch {
+                expected,
+                collected,
+                extra: Some(extra),
+            }),
+        }
+    }
+
+    /// Returns the relation between two `Few`s interpreted as ranges. A `One`
+    /// is treated as a range containing a single value, and a `Zero` as an
+    /// empty range, which is contained by every non-empty range.
+    pub fn relation_to(&self, other: &Few<T>) -> RangeRelation
+        where T: Ord,
+    {
+        let ((a_lo, a_hi), (b_lo, b_hi)) = match (
+            self.min_max(),
+            other.min_max())
+        {
+            (None,    None)    => return RangeRelation::Equal,
+            (None,    Some(_)) => return RangeRelation::ContainedBy,
+            (Some(_), None)    => return RangeRelation::Contains,
+            (Some(a), Some(b)) => (a, b),
+        };
+
+        if a_lo == b_lo && a_hi == b_hi {
+            RangeRelation::Equal
+        } else if a_hi < b_lo {
+            RangeRelation::Before
+        } else if a_lo > b_hi {
+            RangeRelation::After
+        } else if a_lo <= b_lo && a_hi >= b_hi {
+            RangeRelation::Contains
+        } else if a_lo >= b_lo && a_hi <= b_hi {
+            RangeRelation::ContainedBy
+        } else {
+            RangeRelation::Overlaps
+        }
+    }
+
+    /// Maps each contained value with the given function and collects the
+    /// results into a collection.
+    pub fn map_collect<U, C, F>(self, f: F) -> C
+        where
+            C: std::iter::FromIterator<U>,
+            F: FnMut(T) -> U,
+    {
+        self.map(f).collect()
+    }
+
+    /// Returns an iterator over copies of the contained values, leaving the
+    /// `Few` unchanged.
+    pub fn iter_copied(&self) -> impl Iterator<Item=T>
+        where T: Copy,
+    {
+        *self
+    }
+
+    /// Returns an iterator over clones of the contained values, leaving the
+    /// `Few` unchanged.
+    pub fn iter_cloned(&self) -> impl Iterator<Item=T>
+        where T: Clone,
+    {
+        self.clone()
+    }
+
+    /// Adds a value to the `Few` if there is capacity for it, otherwise
+    /// returning the value unchanged.
+    ///
+    /// | Before      | After       | Returns   |
+    /// | ----------- | ----------- | --------- |
+    /// | `Zero`      | `One(x)`    | `None`    |
+    /// | `One(a)`    | `Two(a, x)` | `None`    |
+    /// | `Two(a, b)` | `Two(a, b)` | `Some(x)` |
+    pub fn fill_one_more(&mut self, value: T) -> Option<T> {
+        match std::mem::take(self) {
+            Few::Zero      => { *self = Few::One(value);    None },
+            Few::One(v)    => { *self = Few::Two(v, value); None },
+            Few::Two(a, b) => { *self = Few::Two(a, b);     Some(value) },
         }
     }
-}
 
-impl<T> Into<Option<(T, T)>> for Few<T> where T: Clone {
-    fn into(self) -> Option<(T, T)> {
+    /// Converts a `One` into `Left` and a `Two` into `Right`, returning `None`
+    /// for a `Zero`.
+    #[cfg(feature = "either")]
+    pub fn one_or_two(self) -> Option<Either<T, (T, T)>> {
         match self {
             Few::Zero      => None,
-            Few::One(v)    => Some((v.clone(), v)),
+            Few::One(v)    => Some(Either::Left(v)),
+            Few::Two(a, b) => Some(Either::Right((a, b))),
+        }
+    }
+
+    /// Replaces the contents of the `Few` with the values of the given
+    /// iterator. If the iterator yields more than two values, the `Few` is left
+    /// as a `Zero` and an error holding the first three values is returned.
+    pub fn replace_contents<I>(&mut self, iter: I)
+        -> Result<(), TooManyError<T>>
+        where I: IntoIterator<Item=T>,
+    {
+        let mut iter = iter.into_iter();
+        self.clear();
+        let _ = self.fill_from(&mut iter);
+        match iter.next() {
+            None           => Ok(()),
+            Some(overflow) => Err(TooManyError {
+                collected: std::mem::take(self),
+                overflow,
+            }),
+        }
+    }
+
+    /// Returns a wrapper which formats the contained values as a compact list,
+    /// such as `[1, 2]`, rather than as the variant.
+    pub fn debug_compact(&self) -> impl std::fmt::Debug + '_
+        where T: std::fmt::Debug,
+    {
+        DebugCompact(self)
+    }
+
+    /// Pairs the values of two `Few`s of the same variant, returning both
+    /// `Few`s unchanged as an error if their variants differ.
+    #[allow(clippy::type_complexity)]
+    pub fn try_zip<U>(self, other: Few<U>)
+        -> Result<Few<(T, U)>, (Few<T>, Few<U>)>
+    {
+        match (self, other) {
+            (Few::Zero,      Few::Zero)      => Ok(Few::Zero),
+            (Few::One(a),    Few::One(b))    => Ok(Few::One((a, b))),
+            (Few::Two(a, b), Few::Two(c, d)) => Ok(Few::Two((a, c), (b, d))),
+            (few, other)                     => Err((few, other)),
+        }
+    }
+
+    /// Returns the midpoint of a `Two`, or the value of a `One`. Returns `None`
+    /// for a `Zero`. The midpoint is computed as `lo + (hi - lo) / 2`, so it
+    /// does not overflow as long as the difference of the values is
+    /// representable, and integer midpoints are rounded toward the lower value.
+    /// Floating point values are supported, as the values need only be
+    /// partially ordered.
+    pub fn midpoint(&self) -> Option<T>
+        where T: std::ops::Add<Output=T> + std::ops::Sub<Output=T>
+            + std::ops::Div<Output=T> + PartialOrd + Clone + From<u8>,
+    {
+        let (lo, hi) = match self {
+            Few::Zero                => return None,
+            Few::One(v)              => return Some(v.clone()),
+            Few::Two(a, b) if a <= b => (a, b),
+            Few::Two(a, b)           => (b, a),
+        };
+        Some(lo.clone() + (hi.clone() - lo.clone()) / T::from(2))
+    }
+
+    /// Inserts a value into the `Few`, keeping its values sorted in ascending
+    /// order. If the `Few` is already a `Two`, the largest of the three values
+    /// is evicted and returned, with the inserted value being evicted if it is
+    /// equal to the largest. Returns `None` if there was room for the value.
+    pub fn insert_sorted(&mut self, value: T) -> Option<T>
+        where T: Ord,
+    {
+        self.sort_ascending();
+        let (few, evicted) = match std::mem::take(self) {
+            Few::Zero                    => (Few::One(value), None),
+            Few::One(v) if value < v     => (Few::Two(value, v), None),
+            Few::One(v)                  => (Few::Two(v, value), None),
+            Few::Two(a, b) if value >= b => (Few::Two(a, b), Some(value)),
+            Few::Two(a, b) if value < a  => (Few::Two(value, a), Some(b)),
+            Few::Two(a, b)               => (Few::Two(a, value), Some(b)),
+        };
+        *self = few;
+        evicted
+    }
+
+    /// Splits the `Few` before the first value which does not satisfy the given
+    /// predicate, returning the leading values which satisfy it and the
+    /// remaining values.
+    pub fn split_while<P>(self, mut predicate: P) -> (Few<T>, Few<T>)
+        where P: FnMut(&T) -> bool,
+    {
+        match self {
+            Few::Zero => (Few::Zero, Few::Zero),
+            Few::One(v) => if (predicate)(&v) {
+                (Few::One(v), Few::Zero)
+            } else {
+                (Few::Zero, Few::One(v))
+            },
+            Few::Two(a, b) => if !(predicate)(&a) {
+                (Few::Zero, Few::Two(a, b))
+            } else if !(predicate)(&b) {
+                (Few::One(a), Few::One(b))
+            } else {
+                (Few::Two(a, b), Few::Zero)
+            },
+        }
+    }
+
+    /// Applies a function to each contained value in place, replacing it with
+    /// the result. The process is aborted if the function panics, as the
+    /// values would otherwise be left in an invalid state.
+    pub fn map_owned_in_place<F>(&mut self, mut f: F)
+        where F: FnMut(T) -> T,
+    {
+        replace_with(self, |curr| curr.map(&mut f));
+    }
+
+    /// Returns true if both `Few`s are the same variant and their values are
+    /// pairwise equal according to the given function.
+    ///
+    /// This is not named `eq_by`, as `Few` is an `Iterator` and a method call
+    /// by that name would be taken by `Iterator::eq_by` if it is stabilized.
+    pub fn eq_with<U, F>(&self, other: &Few<U>, mut eq: F) -> bool
+        where F: FnMut(&T, &U) -> bool,
+    {
+        match (self, other) {
+            (Few::Zero,      Few::Zero)      => true,
+            (Few::One(a),    Few::One(b))    => (eq)(a, b),
+            (Few::Two(a, b), Few::Two(c, d)) => (eq)(a, c) && (eq)(b, d),
+            _                                => false,
+        }
+    }
+
+    /// Returns the value at the position given by a typed index.
+    pub fn at<I>(&self, index: I) -> I::Output<'_, T>
+        where I: FewIndex,
+    {
+        index.get(self)
+    }
+
+    /// Returns the bucket containing the given value, where the `Few`
+    /// interpreted as a range divides values into three buckets: `0` for values
+    /// below the range, `1` for values within it, including its bounds, and `2`
+    /// for values above it. A `One` is treated as a range containing a single
+    /// value. Returns `None` for a `Zero`.
+    pub fn bucket_of(&self, value: &T) -> Option<usize>
+        where T: Ord,
+    {
+        let (lo, hi) = self.min_max()?;
+        if value < lo {
+            Some(0)
+        } else if value > hi {
+            Some(2)
+        } else {
+            Some(1)
+        }
+    }
+
+    /// Folds references to the contained values into an accumulator using a
+    /// fallible function, returning the first error encountered.
+    pub fn try_fold_ref<B, E, F>(&self, init: B, mut f: F) -> Result<B, E>
+        where F: FnMut(B, &T) -> Result<B, E>,
+    {
+        match self {
+            Few::Zero      => Ok(init),
+            Few::One(v)    => (f)(init, v),
+            Few::Two(a, b) => {
+                let acc = (f)(init, a)?;
+                (f)(acc, b)
+            },
+        }
+    }
+
+    /// Pairs each contained value with its index offset by the given base.
+    pub fn with_offset(self, base: usize) -> Few<(usize, T)> {
+        match self {
+            Few::Zero      => Few::Zero,
+            Few::One(v)    => Few::One((base, v)),
+            Few::Two(a, b) => Few::Two((base, a), (base + 1, b)),
+        }
+    }
+
+    /// Drops any contained values, leaving the `Few` as a `Zero`.
+    pub fn clear(&mut self) {
+        *self = Few::Zero;
+    }
+
+    /// Shortens the `Few` to at most `len` values, dropping values from the
+    /// back. Does nothing if the `Few` already holds `len` or fewer values.
+    pub fn truncate(&mut self, len: usize) {
+        *self = match (std::mem::take(self), len) {
+            (_,              0) => Few::Zero,
+            (Few::Two(a, _), 1) => Few::One(a),
+            (few,            _) => few,
+        };
+    }
+
+    /// Resizes the `Few` to hold `new_len` values, truncating it if `new_len`
+    /// is smaller than its length, or filling it with values returned by the
+    /// given function if `new_len` is larger.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is greater than two.
+    pub fn resize_with<F>(&mut self, new_len: usize, mut f: F)
+        where F: FnMut() -> T,
+    {
+        assert!(new_len <= 2,
+            "new length {} exceeds the capacity of `Few`", new_len);
+
+        self.truncate(new_len);
+        while self.len() < new_len {
+            let _ = self.fill_one_more((f)());
+        }
+    }
+
+    /// Returns true if the `Few` contains every one of the given values.
+    /// Returns true if no values are given.
+    pub fn contains_all<U>(&self, needles: &[U]) -> bool
+        where U: PartialEq<T>
+    {
+        needles.iter().all(|x| self.contains(x))
+    }
+
+    /// Returns true if the `Few` contains any of the given values. Returns
+    /// false if no values are given.
+    pub fn contains_any<U>(&self, needles: &[U]) -> bool
+        where U: PartialEq<T>
+    {
+        needles.iter().any(|x| self.contains(x))
+    }
+
+    /// Converts the `Few` into an array of two values, filling any absent
+    /// values with clones of the given value.
+    pub fn into_array_padded(self, fill: T) -> [T; 2]
+        where T: Clone,
+    {
+        match self {
+            Few::Zero      => [fill.clone(), fill],
+            Few::One(v)    => [v, fill],
+            Few::Two(a, b) => [a, b],
+        }
+    }
+
+    /// Maps a `Few<T>` to `Few<V>` by applying a function to each contained
+    /// value along with the value at the same position of the given context,
+    /// or `None` if the context has no value at that position.
+    pub fn map_with<U, V, F>(self, mut context: Few<U>, mut f: F) -> Few<V>
+        where F: FnMut(T, Option<U>) -> V,
+    {
+        self.map(|v| (f)(v, context.next()))
+    }
+
+    /// Returns mutable references to both values of a `Two`, or `None` for
+    /// `Zero` and `One` values.
+    pub fn pair_mut(&mut self) -> Option<(&mut T, &mut T)> {
+        match self {
             Few::Two(a, b) => Some((a, b)),
+            _              => None,
+        }
+    }
+
+    /// Applies a function to mutable references to both values of a `Two`,
+    /// returning true if the function was applied.
+    pub fn adjust_pair<F>(&mut self, mut f: F) -> bool
+        where F: FnMut(&mut T, &mut T),
+    {
+        match self.pair_mut() {
+            Some((a, b)) => { (f)(a, b); true },
+            None         => false,
         }
     }
+
+    /// Converts from `&Few<T>` to `Few<&T>`.
+    fn as_ref(&self) -> Few<&T> {
+        match self {
+            Few::Zero      => Few::Zero,
+            Few::One(v)    => Few::One(v),
+            Few::Two(a, b) => Few::Two(a, b),
+        }
+    }
+
+    /// Pairs the values of two `Few`s of the same variant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `Few`s are different variants.
+    #[track_caller]
+    pub fn zip_eq<U>(self, other: Few<U>) -> Few<(T, U)> {
+        match self.try_zip(other) {
+            Ok(zipped)  => zipped,
+            Err((a, b)) => panic!(
+                "`zip_eq` called on `Few`s of different lengths: {} and {}",
+                a.len(),
+                b.len()),
+        }
+    }
+
+    /// Applies a function to each of the two positions of the `Few`, passing
+    /// `Some` value for present values and `None` for absent ones, and returns
+    /// both results.
+    pub fn to_pair_map<U, F>(self, mut f: F) -> (U, U)
+        where F: FnMut(Option<T>) -> U,
+    {
+        match self {
+            Few::Zero      => { let a = (f)(None);    (a, (f)(None)) },
+            Few::One(v)    => { let a = (f)(Some(v)); (a, (f)(None)) },
+            Few::Two(a, b) => { let a = (f)(Some(a)); (a, (f)(Some(b))) },
+        }
+    }
+
+    /// Splits the `Few` into its first and second values by position.
+    pub fn into_first_and_rest(self) -> (Option<T>, Option<T>) {
+        match self {
+            Few::Zero      => (None, None),
+            Few::One(v)    => (Some(v), None),
+            Few::Two(a, b) => (Some(a), Some(b)),
+        }
+    }
+
+    /// Constructs a `Few` from its first and second values by position. This
+    /// is the inverse of [`Few::into_first_and_rest`]. A second value without a
+    /// first value is moved into the first position, giving a `One`.
+    pub fn from_first_and_rest(first: Option<T>, rest: Option<T>) -> Few<T> {
+        Few::from((first, rest))
+    }
+
+    /// Removes the values which do not satisfy the given predicate, preserving
+    /// the order of the remaining values, and returns the number of values
+    /// removed.
+    pub fn retain_counting<P>(&mut self, mut predicate: P) -> usize
+        where P: FnMut(&T) -> bool,
+    {
+        let len = self.len();
+        *self = match std::mem::take(self) {
+            Few::Zero      => Few::Zero,
+            Few::One(v)    => Few::from(Some(v).filter(&mut predicate)),
+            Few::Two(a, b) => {
+                let a = Some(a).filter(&mut predicate);
+                Few::from((a, Some(b).filter(&mut predicate)))
+            },
+        };
+        len - self.len()
+    }
+
+    /// Maps a `Few<T>` to `Few<U>` by applying a function to each contained
+    /// value in order while threading an accumulator through each call,
+    /// returning the final accumulator along with the mapped `Few`.
+    pub fn map_accumulate<S, U, F>(self, init: S, mut f: F) -> (S, Few<U>)
+        where F: FnMut(S, T) -> (S, U),
+    {
+        match self {
+            Few::Zero      => (init, Few::Zero),
+            Few::One(v)    => {
+                let (acc, v) = (f)(init, v);
+                (acc, Few::One(v))
+            },
+            Few::Two(a, b) => {
+                let (acc, a) = (f)(init, a);
+                let (acc, b) = (f)(acc, b);
+                (acc, Few::Two(a, b))
+            },
+        }
+    }
+
+    /// Returns the number of the given values which lie within the `Few`
+    /// interpreted as an inclusive range. A `One` is treated as a range
+    /// containing a single value, and a `Zero` as an empty range.
+    pub fn count_within(&self, values: &[T]) -> usize
+        where T: Ord,
+    {
+        values.iter().filter(|v| self.bucket_of(v) == Some(1)).count()
+    }
+
+    /// Returns `parts + 1` evenly spaced points from the first value of a `Two`
+    /// to its second value, inclusive. Returns the value of a `One`, or nothing
+    /// for a `Zero`.
+    ///
+    /// This is only supported for integer types. Each point is
+    /// `lo + (hi - lo) * i / parts` for the lower and upper bounds `lo` and
+    /// `hi`, rounded toward the lower bound. The points are computed without
+    /// overflow as long as the difference of the values is representable.
+    ///
+    /// The point spacing is computed in `T`, so `None` is returned for a `Two`
+    /// if `parts` cannot be represented by `T`, such as `256` parts of a
+    /// `Few<u8>`, rather than returning incorrect points or panicking.
+    pub fn subdivide(&self, parts: usize) -> Option<Vec<T>>
+        where T: std::ops::Add<Output=T> + std::ops::Sub<Output=T>
+            + std::ops::Div<Output=T> + std::ops::Rem<Output=T>
+            + Ord + Clone + From<u8> + std::convert::TryFrom<usize>,
+    {
+        let reversed = match self {
+            Few::Zero                    => return Some(Vec::new()),
+            Few::One(v)                  => return Some(vec![v.clone()]),
+            Few::Two(a, _) if parts == 0 => return Some(vec![a.clone()]),
+            Few::Two(a, b)               => a > b,
+        };
+        let (lo, hi) = self.min_max()?;
+        let total = T::try_from(parts).ok()?;
+
+        // Rather than multiplying the difference, which may overflow, each
+        // point is stepped to from the last by the quotient of the difference,
+        // with the remainder carried until it accounts for another unit.
+        let diff = hi.clone() - lo.clone();
+        let step = diff.clone() / total.clone();
+        let remainder = diff % total.clone();
+        let carry_limit = total - remainder.clone();
+        let mut carry = T::from(0);
+        let mut point = lo.clone();
+        let mut points = Vec::with_capacity(parts.saturating_add(1));
+        points.push(point.clone());
+        for _ in 0..parts {
+            point = point + step.clone();
+            if carry >= carry_limit {
+                carry = carry - carry_limit.clone();
+                point = point + T::from(1);
+            } else {
+                carry = carry + remainder.clone();
+            }
+            points.push(point.clone());
+        }
+
+        if reversed { points.reverse(); }
+        Some(points)
+    }
+
+    /// Fills any remaining capacity of the `Few` with clones of values from the
+    /// given slice. If the slice holds more values than will fit, the `Few` is
+    /// filled and the number of values left over is returned as an error.
+    pub fn try_extend_from_slice(&mut self, slice: &[T]) -> Result<(), usize>
+        where T: Clone,
+    {
+        let added = self.fill_from(slice.iter().cloned());
+        match slice.len() - added {
+            0      => Ok(()),
+            excess => Err(excess),
+        }
+    }
+
+    /// Returns an iterator which always yields two items: each contained value
+    /// in `Some`, followed by `None` for each absent value.
+    pub fn iter_padded(self) -> impl Iterator<Item=Option<T>> {
+        let (first, rest) = self.into_first_and_rest();
+        Few::Two(first, rest)
+    }
+
+    /// Collapses a `Two` into a `One` holding its first value if the given
+    /// function returns true for its values.
+    pub fn dedup_by<F>(&mut self, mut same: F)
+        where F: FnMut(&T, &T) -> bool,
+    {
+        if self.combine_ref(&mut same) == Some(true) {
+            self.truncate(1);
+        }
+    }
+
+    /// Converts a `Few<T>` to `Few<U>` by converting each contained value with
+    /// `TryInto`, returning the first conversion error encountered.
+    pub fn try_into_few<U>(self)
+        -> Result<Few<U>, <T as std::convert::TryInto<U>>::Error>
+        where T: std::convert::TryInto<U>,
+    {
+        match self {
+            Few::Zero      => Ok(Few::Zero),
+            Few::One(v)    => Ok(Few::One(v.try_into()?)),
+            Few::Two(a, b) => {
+                let a = a.try_into()?;
+                Ok(Few::Two(a, b.try_into()?))
+            },
+        }
+    }
+
+    /// Returns an inclusive range of references to the normalized bounds of the
+    /// `Few`, or `None` if it is empty. A `One` gives a degenerate range.
+    pub fn range_refs(&self) -> Option<std::ops::RangeInclusive<&T>>
+        where T: Ord,
+    {
+        self.min_max().map(|(lo, hi)| lo..=hi)
+    }
+
+    /// Replaces the `Few` with the given value if it is empty. Returns `true`
+    /// if the replacement occurred.
+    pub fn replace_if_zero(&mut self, value: Few<T>) -> bool {
+        if self.is_zero() {
+            *self = value;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns an owning iterator over the values of the `Few` in reverse
+    /// order.
+    pub fn into_iter_rev(self) -> IntoIterRev<T> {
+        IntoIterRev { inner: self }
+    }
+
+    /// Swaps the contents of the `Few` with those of another.
+    pub fn swap_with(&mut self, other: &mut Few<T>) {
+        std::mem::swap(self, other);
+    }
+
+    /// Asserts that the second value of a `Two` directly follows its first
+    /// value in memory, as relied on by [`Few::as_ptr`] and
+    /// [`Few::as_cow_slice`]. A `Zero` or `One` holds at most one value, so
+    /// there is nothing to check for them.
+    ///
+    /// This is a no-op in release builds.
+    pub fn debug_invariants(&self) {
+        if let Few::Two(a, b) = self {
+            let a: *const T = a;
+            debug_assert!(std::ptr::eq(a.wrapping_add(1), b),
+                "second value of `Two` does not follow the first");
+        }
+    }
+
+    /// Constructs a `Two` from the given values, with the lesser value first.
+    /// Equal values are not collapsed into a `One`.
+    pub fn pair_sorted(a: T, b: T) -> Few<T>
+        where T: Ord,
+    {
+        if a <= b { Few::Two(a, b) } else { Few::Two(b, a) }
+    }
+
+    /// Takes both values out of a `Two`, leaving a `Zero` in its place. Returns
+    /// `None` and leaves the `Few` unchanged if it is not a `Two`.
+    pub fn take_pair(&mut self) -> Option<(T, T)> {
+        match std::mem::take(self) {
+            Few::Two(a, b) => Some((a, b)),
+            other          => { *self = other; None },
+        }
+    }
+
+    /// Maps the contained values which satisfy the given predicate using the
+    /// given function, leaving the other values unchanged.
+    pub fn map_if<P, F>(self, mut predicate: P, mut f: F) -> Few<T>
+        where
+            P: FnMut(&T) -> bool,
+            F: FnMut(T) -> T,
+    {
+        let mut apply = |v: T| if (predicate)(&v) { (f)(v) } else { v };
+        match self {
+            Few::Zero      => Few::Zero,
+            Few::One(v)    => Few::One(apply(v)),
+            Few::Two(a, b) => {
+                let a = apply(a);
+                Few::Two(a, apply(b))
+            },
+        }
+    }
+
+    /// Returns the index of the partition point according to the given
+    /// predicate, which is the index of the first value for which the
+    /// predicate returns `false`.
+    ///
+    /// As with [`slice::partition_point`], the `Few` is assumed to be
+    /// partitioned according to the predicate, with all values for which it
+    /// returns `true` preceding all values for which it returns `false`. If
+    /// this is not the case, the result is unspecified.
+    pub fn partition_point<P>(&self, mut predicate: P) -> usize
+        where P: FnMut(&T) -> bool,
+    {
+        match self {
+            Few::Zero                          => 0,
+            Few::One(v) if (predicate)(v)      => 1,
+            Few::One(_)                        => 0,
+            Few::Two(a, _) if !(predicate)(a)  => 0,
+            Few::Two(_, b) if !(predicate)(b)  => 1,
+            Few::Two(_, _)                     => 2,
+        }
+    }
+
+    /// Returns an iterator over mutable references to the values of the `Few`
+    /// in reverse order.
+    pub fn iter_mut_rev(&mut self) -> IterMutRev<'_, T> {
+        // Matching on `self` splits the borrow into disjoint references to
+        // each field, so no `unsafe` is needed.
+        let inner = match self {
+            Few::Zero      => Few::Zero,
+            Few::One(v)    => Few::One(v),
+            Few::Two(a, b) => Few::Two(a, b),
+        };
+        IterMutRev { inner }
+    }
+
+    /// Returns the number of contained values equal to the given value.
+    pub fn contains_count(&self, value: &T) -> usize
+        where T: PartialEq,
+    {
+        self.as_ref().filter(|v| *v == value).count()
+    }
+
+    /// Returns the value interpolated between the values of a `Two` at the
+    /// given fraction `t`, computed as `a + t * (b - a)`. Values of `t` outside
+    /// of `[0, 1]` extrapolate beyond the bounds. Returns the value of a `One`
+    /// for any `t`, and `None` for a `Zero`.
+    ///
+    /// The computation is done in `f64`, so `T` must convert both to and from
+    /// `f64`. Of the primitive types, only `f64` itself does so, but any type
+    /// wrapping an `f64` may implement the conversions.
+    pub fn lerp(&self, t: f64) -> Option<T>
+        where T: Into<f64> + From<f64> + Clone,
+    {
+        match self {
+            Few::Zero      => None,
+            Few::One(v)    => Some(v.clone()),
+            Few::Two(a, b) => {
+                let a: f64 = a.clone().into();
+                let b: f64 = b.clone().into();
+                Some(T::from(a + t * (b - a)))
+            },
+        }
+    }
+
+    /// Returns every value in the normalized inclusive range of the `Few`. A
+    /// `One` gives its value, and a `Zero` gives no values.
+    ///
+    /// All values in the range are collected, so this should be avoided for
+    /// potentially large ranges.
+    pub fn expand_inclusive(&self) -> Vec<T>
+        where
+            T: Ord + Clone,
+            std::ops::RangeInclusive<T>: Iterator<Item=T>,
+    {
+        match self.min_max() {
+            Some((lo, hi)) => (lo.clone()..=hi.clone()).collect(),
+            None           => Vec::new(),
+        }
+    }
+
+    /// Maps each contained value to an iterable using the given function, and
+    /// collects all of the resulting values into a `Vec`.
+    pub fn flat_map_to_vec<U, I, F>(self, mut f: F) -> Vec<U>
+        where
+            F: FnMut(T) -> I,
+            I: IntoIterator<Item=U>,
+    {
+        let mut values = Vec::new();
+        for v in self {
+            // `extend` reserves space using the iterator's size hint.
+            values.extend((f)(v));
+        }
+        values
+    }
+
+    /// Returns the name of the `Few`'s variant.
+    pub const fn variant_name(&self) -> &'static str {
+        match self {
+            Few::Zero      => "Zero",
+            Few::One(_)    => "One",
+            Few::Two(_, _) => "Two",
+        }
+    }
+
+    /// Returns references to the values of a `Two`, or an error naming the
+    /// actual variant otherwise.
+    pub fn try_pair(&self) -> Result<(&T, &T), FewError> {
+        match self {
+            Few::Two(a, b) => Ok((a, b)),
+            _              => Err(FewError {
+                expected: "Two",
+                found: self.variant_name(),
+            }),
+        }
+    }
+
+    /// Maps the values of the `Few` to a pair using the given function, filling
+    /// any missing positions with the given default.
+    pub fn map_pair_or<U, F>(self, default: U, mut f: F) -> (U, U)
+        where
+            U: Clone,
+            F: FnMut(T) -> U,
+    {
+        match self {
+            Few::Zero      => (default.clone(), default),
+            Few::One(v)    => ((f)(v), default),
+            Few::Two(a, b) => {
+                let a = (f)(a);
+                (a, (f)(b))
+            },
+        }
+    }
+
+    /// Collapses a `Two` into a `One` holding the value with the maximum key
+    /// according to the given function, keeping the second value if the keys
+    /// are equal. Other variants are unchanged.
+    pub fn keep_extreme_by_key<K, F>(self, key: F) -> Few<T>
+        where
+            K: Ord,
+            F: FnMut(&T) -> K,
+    {
+        Few::from(self.max_by_key(key))
+    }
+
+    /// Collapses a `Two` into a `One` holding the value with the minimum key
+    /// according to the given function, keeping the first value if the keys
+    /// are equal. Other variants are unchanged.
+    pub fn keep_min_by_key<K, F>(self, key: F) -> Few<T>
+        where
+            K: Ord,
+            F: FnMut(&T) -> K,
+    {
+        Few::from(self.min_by_key(key))
+    }
+
+    /// Returns the `Few` if it is a non-empty range, or `None` otherwise.
+    ///
+    /// A `Zero` and a `Two` with equal values are considered empty ranges. A
+    /// `One` is not, and is returned unchanged.
+    pub fn ensure_nonempty_range(self) -> Option<Few<T>>
+        where T: PartialEq,
+    {
+        match &self {
+            Few::Zero                => None,
+            Few::Two(a, b) if a == b => None,
+            _                        => Some(self),
+        }
+    }
+
+    /// Pairs the values of two `Few`s by position and maps each pair and its
+    /// index using the given function. The result has the length of the
+    /// shorter `Few`.
+    pub fn zip_indexed_map<U, V, F>(self, other: Few<U>, mut f: F) -> Few<V>
+        where F: FnMut(usize, T, U) -> V,
+    {
+        match (self, other) {
+            (Few::Two(a, b), Few::Two(c, d)) => {
+                let first = (f)(0, a, c);
+                Few::Two(first, (f)(1, b, d))
+            },
+            (Few::One(a),    Few::One(c))    |
+            (Few::One(a),    Few::Two(c, _)) |
+            (Few::Two(a, _), Few::One(c))    => Few::One((f)(0, a, c)),
+            _                                => Few::Zero,
+        }
+    }
+
+    /// Returns the contained values in ascending order as a `Vec`.
+    pub fn into_sorted_vec(mut self) -> Vec<T>
+        where T: Ord,
+    {
+        self.sort_ascending();
+        self.collect()
+    }
+
+    /// Returns the contained values as a `Vec`, with a separator generated by
+    /// the given function between the values of a `Two`. The function is only
+    /// called for a `Two`.
+    pub fn to_vec_interspersed_with<F>(self, mut sep: F) -> Vec<T>
+        where F: FnMut() -> T,
+    {
+        match self {
+            Few::Zero      => Vec::new(),
+            Few::One(v)    => vec![v],
+            Few::Two(a, b) => vec![a, (sep)(), b],
+        }
+    }
+
+    /// Compares two `Few`s interpreted as ranges, ordering by lower bound and
+    /// then by upper bound. The bounds of a `Two` are normalized, a `One` is
+    /// treated as a range containing a single value, and a `Zero` is ordered
+    /// before every non-empty range.
+    pub fn cmp_by_range(&self, other: &Few<T>) -> std::cmp::Ordering
+        where T: Ord,
+    {
+        self.min_max().cmp(&other.min_max())
+    }
+}
+
+impl<T, E> Few<Result<T, E>> {
+    /// Maps a `Few<Result<T, E>>` to `Few<Result<U, E>>` by applying a function
+    /// to each contained `Ok` value, leaving `Err` values unchanged.
+    pub fn map_ok<U, F>(self, mut f: F) -> Few<Result<U, E>>
+        where F: FnMut(T) -> U,
+    {
+        self.map(|res| res.map(&mut f))
+    }
+
+    /// Returns an iterator over the contained `Ok` values, discarding any `Err`
+    /// values.
+    pub fn into_ok_iter(self) -> impl Iterator<Item=T> {
+        self.filter_map(Result::ok)
+    }
+
+    /// Returns an iterator over the contained `Err` values, discarding any `Ok`
+    /// values.
+    pub fn into_err_iter(self) -> impl Iterator<Item=E> {
+        self.filter_map(Result::err)
+    }
+}
+
+impl<T> Few<Option<T>> {
+    /// Returns an iterator over references to the contained `Some` values,
+    /// skipping any `None` values.
+    pub fn iter_present(&self) -> impl Iterator<Item=&T> + '_ {
+        self.as_ref().filter_map(Option::as_ref)
+    }
+}
+
+impl Few<bool> {
+    /// Packs the `Few` into the low bits of a byte.
+    ///
+    /// Bits 0 and 1 hold the number of values, bit 2 holds the first value, and
+    /// bit 3 holds the second value. All other bits, including those of absent
+    /// values, are zero.
+    pub fn to_bits(self) -> u8 {
+        match self {
+            Few::Zero      => 0b00,
+            Few::One(v)    => 0b01 | (v as u8) << 2,
+            Few::Two(a, b) => 0b10 | (a as u8) << 2 | (b as u8) << 3,
+        }
+    }
+
+    /// Unpacks a `Few` from a byte produced by [`Few::to_bits`], returning
+    /// `None` if the byte is not a valid encoding.
+    pub fn from_bits(byte: u8) -> Option<Few<bool>> {
+        let first = byte & 0b0100 != 0;
+        let second = byte & 0b1000 != 0;
+        let few = match byte & 0b11 {
+            0b00 => Few::Zero,
+            0b01 => Few::One(first),
+            0b10 => Few::Two(first, second),
+            _    => return None,
+        };
+        if few.to_bits() == byte { Some(few) } else { None }
+    }
+}
+
+impl<T> Iterator for Few<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let mut res = None;
+        replace_with(self, |curr|
+            match curr {
+                Few::Zero      => { res = None;    Few::Zero },
+                Few::One(v)    => { res = Some(v); Few::Zero },
+                Few::Two(a, b) => { res = Some(a); Few::One(b) },
+            }
+        );
+        res
+    }
+}
+
+impl<T> DoubleEndedIterator for Few<T> {
+    fn next_back(&mut self) -> Option<T> {
+        let mut res = None;
+        replace_with(self, |curr|
+            match curr {
+                Few::Zero      => { res = None;    Few::Zero },
+                Few::One(v)    => { res = Some(v); Few::Zero },
+                Few::Two(a, b) => { res = Some(b); Few::One(a) },
+            }
+        );
+        res
+    }
+}
+
+impl<T> ExactSizeIterator for Few<T> {
+    fn len(&self) -> usize {
+        match self {
+            Few::Zero      => 0,
+            Few::One(_)    => 1,
+            Few::Two(_, _) => 2,
+        }
+    }
+}
+
+impl<T> std::iter::FusedIterator for Few<T> {}
+
+
+impl<T> From<T> for Few<T> {
+    fn from(value: T) -> Self {
+        Few::One(value)
+    }
+}
+
+impl<T> From<(T, T)> for Few<T> {
+    fn from(value: (T, T)) -> Self {
+        Few::Two(value.0, value.1)
+    }
+}
+
+impl<T> From<Option<T>> for Few<T> {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            None        => Few::Zero,
+            Some(value) => Few::One(value),
+        }
+    }
+}
+
+impl<T> From<Option<(T, T)>> for Few<T> {
+    fn from(value: Option<(T, T)>) -> Self {
+        match value {
+            None         => Few::Zero,
+            Some((a, b)) => Few::Two(a, b),
+        }
+    }
+}
+
+impl<T> From<(Option<T>, Option<T>)> for Few<T> {
+    fn from(value: (Option<T>, Option<T>)) -> Self {
+        match (value.0, value.1) {
+            (None,    None)    => Few::Zero,
+            (Some(a), None)    => Few::One(a),
+            (None,    Some(b)) => Few::One(b),
+            (Some(a), Some(b)) => Few::Two(a, b),
+        }
+    }
+}
+
+impl<T> From<Few<T>> for Option<(T, T)> where T: Clone {
+    fn from(value: Few<T>) -> Self {
+        match value {
+            Few::Zero      => None,
+            Few::One(v)    => Some((v.clone(), v)),
+            Few::Two(a, b) => Some((a, b)),
+        }
+    }
+}
+
+/// A `Two(a, b)` is treated as the inclusive range `a..=b`, and a `One(v)` as
+/// the inclusive range `v..=v`. The values of a `Two` are not reordered, so
+/// [`Few::sort_ascending`] may be needed before use with a collection which
+/// requires the start bound to precede the end bound.
+///
+/// # Panics
+///
+/// A `Zero` has no values to use as bounds, so `start_bound` and `end_bound`
+/// will panic if called on a `Zero`.
+impl<T> std::ops::RangeBounds<T> for Few<T> {
+    fn start_bound(&self) -> std::ops::Bound<&T> {
+        match self {
+            Few::Zero      => panic!("`Zero` cannot be used as a range bound"),
+            Few::One(v)    => std::ops::Bound::Included(v),
+            Few::Two(a, _) => std::ops::Bound::Included(a),
+        }
+    }
+
+    fn end_bound(&self) -> std::ops::Bound<&T> {
+        match self {
+            Few::Zero      => panic!("`Zero` cannot be used as a range bound"),
+            Few::One(v)    => std::ops::Bound::Included(v),
+            Few::Two(_, b) => std::ops::Bound::Included(b),
+        }
+    }
+}
+
+/// Allows a `Few` to be borrowed as a range, as with `map.range(&few)`.
+///
+/// # Panics
+///
+/// As for `Few`, `start_bound` and `end_bound` will panic if called on a
+/// reference to a `Zero`.
+impl<T> std::ops::RangeBounds<T> for &Few<T> {
+    fn start_bound(&self) -> std::ops::Bound<&T> {
+        (**self).start_bound()
+    }
+
+    fn end_bound(&self) -> std::ops::Bound<&T> {
+        (**self).end_bound()
+    }
+}
+
+#[cfg(feature = "either")]
+impl<T> From<Either<T, (T, T)>> for Few<T> {
+    fn from(value: Either<T, (T, T)>) -> Self {
+        match value {
+            Either::Left(v)       => Few::One(v),
+            Either::Right((a, b)) => Few::Two(a, b),
+        }
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> AbsDiffEq for Few<T>
+    where
+        T: AbsDiffEq,
+        T::Epsilon: Clone,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.eq_with(other, |a, b| a.abs_diff_eq(b, epsilon.clone()))
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<T> RelativeEq for Few<T>
+    where
+        T: RelativeEq,
+        T::Epsilon: Clone,
+{
+    fn default_max_relative() -> T::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: T::Epsilon,
+        max_relative: T::Epsilon)
+        -> bool
+    {
+        self.eq_with(other, |a, b| a.relative_eq(
+            b,
+            epsilon.clone(),
+            max_relative.clone()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> Deserialize<'de> for Few<T> where T: Deserialize<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: serde::Deserializer<'de>,
+    {
+        use serde::de::{ EnumAccess, VariantAccess, Visitor };
+
+        #[derive(Deserialize)]
+        #[serde(variant_identifier)]
+        enum Variant { Zero, One, Two }
+
+        struct FewVisitor<T>(std::marker::PhantomData<T>);
+
+        impl<'de, T> Visitor<'de> for FewVisitor<T> where T: Deserialize<'de> {
+            type Value = Few<T>;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>)
+                -> std::fmt::Result
+            {
+                f.write_str("enum Few")
+            }
+
+            fn visit_enum<A>(self, data: A) -> Result<Few<T>, A::Error>
+                where A: EnumAccess<'de>,
+            {
+                match data.variant()? {
+                    (Variant::Zero, variant) => variant
+                        .unit_variant()
+                        .map(|_| Few::Zero),
+                    (Variant::One, variant) => variant
+                        .newtype_variant()
+                        .map(Few::One),
+                    (Variant::Two, variant) => variant
+                        .tuple_variant(2, TwoVisitor(std::marker::PhantomData))
+                        .map(|(a, b)| Few::Two(a, b)),
+                }
+            }
+        }
+
+        deserializer.deserialize_enum(
+            "Few",
+            &["Zero", "One", "Two"],
+            FewVisitor(std::marker::PhantomData))
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Drain
+////////////////////////////////////////////////////////////////////////////////
+/// A draining iterator over the values of a `Few`.
+///
+/// This is returned by [`Few::drain`].
+#[derive(Debug)]
+pub struct Drain<'a, T> {
+    inner: Few<T>,
+    marker: std::marker::PhantomData<&'a mut Few<T>>,
+}
+
+impl<'a, T> Iterator for Drain<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Drain<'a, T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.inner.next_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Drain<'a, T> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<'a, T> std::iter::FusedIterator for Drain<'a, T> {}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntoIterRev
+////////////////////////////////////////////////////////////////////////////////
+/// An owning iterator over the values of a `Few` in reverse order.
+///
+/// This is returned by [`Few::into_iter_rev`].
+#[derive(Debug, Clone)]
+pub struct IntoIterRev<T> {
+    inner: Few<T>,
+}
+
+impl<T> Iterator for IntoIterRev<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.inner.next_back()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIterRev<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.inner.next()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIterRev<T> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<T> std::iter::FusedIterator for IntoIterRev<T> {}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IterMutRev
+////////////////////////////////////////////////////////////////////////////////
+/// An iterator over mutable references to the values of a `Few` in reverse
+/// order.
+///
+/// This is returned by [`Few::iter_mut_rev`].
+#[derive(Debug)]
+pub struct IterMutRev<'a, T> {
+    inner: Few<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMutRev<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.inner.next_back()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMutRev<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        self.inner.next()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMutRev<'a, T> {
+    fn len(&self) -> usize {
+        self.inner.len()
+    }
+}
+
+impl<'a, T> std::iter::FusedIterator for IterMutRev<'a, T> {}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// NonEmptyFew
+////////////////////////////////////////////////////////////////////////////////
+/// A type which contains one or two of a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum NonEmptyFew<T> {
+    /// One value present.
+    One(T),
+    /// Two values present.
+    Two(T, T),
+}
+
+impl<T> NonEmptyFew<T> {
+    /// Returns a reference to the first value.
+    pub fn first(&self) -> &T {
+        match self {
+            NonEmptyFew::One(v)    => v,
+            NonEmptyFew::Two(a, _) => a,
+        }
+    }
+}
+
+impl<T> From<NonEmptyFew<T>> for Few<T> {
+    fn from(value: NonEmptyFew<T>) -> Self {
+        match value {
+            NonEmptyFew::One(v)    => Few::One(v),
+            NonEmptyFew::Two(a, b) => Few::Two(a, b),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> Deserialize<'de> for NonEmptyFew<T> where T: Deserialize<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: serde::Deserializer<'de>,
+    {
+        use serde::de::{ EnumAccess, VariantAccess, Visitor };
+
+        #[derive(Deserialize)]
+        #[serde(variant_identifier)]
+        enum Variant { One, Two }
+
+        struct NonEmptyFewVisitor<T>(std::marker::PhantomData<T>);
+
+        impl<'de, T> Visitor<'de> for NonEmptyFewVisitor<T>
+            where T: Deserialize<'de>,
+        {
+            type Value = NonEmptyFew<T>;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter<'_>)
+                -> std::fmt::Result
+            {
+                f.write_str("enum NonEmptyFew")
+            }
+
+            fn visit_enum<A>(self, data: A) -> Result<NonEmptyFew<T>, A::Error>
+                where A: EnumAccess<'de>,
+            {
+                match data.variant()? {
+                    (Variant::One, variant) => variant
+                        .newtype_variant()
+                        .map(NonEmptyFew::One),
+                    (Variant::Two, variant) => variant
+                        .tuple_variant(2, TwoVisitor(std::marker::PhantomData))
+                        .map(|(a, b)| NonEmptyFew::Two(a, b)),
+                }
+            }
+        }
+
+        deserializer.deserialize_enum(
+            "NonEmptyFew",
+            &["One", "Two"],
+            NonEmptyFewVisitor(std::marker::PhantomData))
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// LenMismatch
+////////////////////////////////////////////////////////////////////////////////
+/// An error returned when an iterator does not yield the expected number of
+/// values.
+///
+/// This is returned by [`Few::from_iter_exact`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LenMismatch<T> {
+    /// The number of values expected.
+    pub expected: usize,
+    /// The values collected before the mismatch was detected.
+    pub collected: Few<T>,
+    /// The first value beyond the expected number, if too many were found.
+    pub extra: Option<T>,
+}
+
+impl<T> std::fmt::Display for LenMismatch<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.expected > 2 {
+            write!(f, "expected {} values, which exceeds the capacity of `Few`",
+                self.expected)
+        } else if self.extra.is_some() {
+            write!(f, "expected {} values, found more", self.expected)
+        } else {
+            write!(f, "expected {} values, found {}",
+                self.expected,
+                self.collected.len())
+        }
+    }
+}
+
+impl<T> std::error::Error for LenMismatch<T> where T: std::fmt::Debug {}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// RangeRelation
+////////////////////////////////////////////////////////////////////////////////
+/// The relation between two `Few`s interpreted as ranges.
+///
+/// This is returned by [`Few::relation_to`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RangeRelation {
+    /// The range lies entirely before the other range.
+    Before,
+    /// The range partially overlaps the other range.
+    Overlaps,
+    /// The range contains the other range.
+    Contains,
+    /// The range is contained by the other range.
+    ContainedBy,
+    /// The range lies entirely after the other range.
+    After,
+    /// The range is equal to the other range.
+    Equal,
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// TooManyError
+////////////////////////////////////////////////////////////////////////////////
+/// An error returned when an iterator yields more values than a `Few` can hold.
+///
+/// This is returned by [`Few::replace_contents`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TooManyError<T> {
+    /// The values collected before the overflow.
+    pub collected: Few<T>,
+    /// The first value which did not fit.
+    pub overflow: T,
+}
+
+impl<T> std::fmt::Display for TooManyError<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "too many values for `Few`, expected at most 2")
+    }
+}
+
+impl<T> std::error::Error for TooManyError<T> where T: std::fmt::Debug {}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// FewError
+////////////////////////////////////////////////////////////////////////////////
+/// An error returned when a `Few` is not of the expected variant.
+///
+/// This is returned by [`Few::try_pair`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FewError {
+    /// The name of the expected variant.
+    pub expected: &'static str,
+    /// The name of the variant found.
+    pub found: &'static str,
+}
+
+impl std::fmt::Display for FewError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "expected `Few::{}`, found `Few::{}`",
+            self.expected,
+            self.found)
+    }
+}
+
+impl std::error::Error for FewError {}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// DebugCompact
+////////////////////////////////////////////////////////////////////////////////
+/// Formats the values of a `Few` as a list.
+///
+/// This is returned by [`Few::debug_compact`].
+struct DebugCompact<'a, T>(&'a Few<T>);
+
+impl<'a, T> std::fmt::Debug for DebugCompact<'a, T>
+    where T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Few::Zero      => f.debug_list().finish(),
+            Few::One(v)    => f.debug_list().entry(v).finish(),
+            Few::Two(a, b) => f.debug_list().entry(a).entry(b).finish(),
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// FewIndex
+////////////////////////////////////////////////////////////////////////////////
+/// A typed position within a `Few`.
+///
+/// This is used by [`Few::at`].
+pub trait FewIndex {
+    /// The type returned when accessing the position.
+    type Output<'a, T> where T: 'a;
+
+    /// Accesses the position within the given `Few`.
+    fn get<T>(self, few: &Few<T>) -> Self::Output<'_, T>;
+}
+
+/// The position of the first value of a `Few`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct First;
+
+impl FewIndex for First {
+    type Output<'a, T> = Option<&'a T> where T: 'a;
+
+    fn get<T>(self, few: &Few<T>) -> Option<&T> {
+        few.peek()
+    }
+}
+
+/// The position of the second value of a `Few`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Second;
+
+impl FewIndex for Second {
+    type Output<'a, T> = Option<&'a T> where T: 'a;
+
+    fn get<T>(self, few: &Few<T>) -> Option<&T> {
+        match few {
+            Few::Two(_, b) => Some(b),
+            _              => None,
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// chunk_by_key
+////////////////////////////////////////////////////////////////////////////////
+/// Groups a slice into maximal runs of elements with equal keys, returning the
+/// first and last element of each run as a `Two`, or a `One` for runs of a
+/// single element.
+pub fn chunk_by_key<T, K, F>(items: &[T], key: F) -> Vec<Few<T>>
+    where
+        T: Clone,
+        K: PartialEq,
+        F: Fn(&T) -> K,
+{
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while start < items.len() {
+        let run_key = (key)(&items[start]);
+        let mut end = start + 1;
+        while end < items.len() && (key)(&items[end]) == run_key {
+            end += 1;
+        }
+
+        if end - start == 1 {
+            chunks.push(Few::One(items[start].clone()));
+        } else {
+            chunks.push(Few::Two(items[start].clone(), items[end - 1].clone()));
+        }
+        start = end;
+    }
+    chunks
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// every_other
+////////////////////////////////////////////////////////////////////////////////
+/// Returns an iterator pairing the items of the given iterator at a stride of
+/// two.
+///
+/// Items are consumed in blocks of four, with each block yielding a `Few` of
+/// its first and third items followed by a `Few` of its second and fourth
+/// items. So the items `0, 1, 2, 3, 4, 5, 6, 7` are grouped as `Two(0, 2)`,
+/// `Two(1, 3)`, `Two(4, 6)`, `Two(5, 7)`. A trailing partial block yields
+/// `One`s for any items without a partner, so the items `0, 1, 2` are grouped
+/// as `Two(0, 2)`, `One(1)`.
+pub fn every_other<I>(iter: I) -> impl Iterator<Item=Few<I::Item>>
+    where I: Iterator,
+{
+    let mut iter = iter.fuse();
+    let mut pending = None;
+    std::iter::from_fn(move || {
+        if let Some(few) = pending.take() {
+            return Some(few);
+        }
+        let first = iter.next()?;
+        let second = iter.next();
+        let third = iter.next();
+        let fourth = iter.next();
+        pending = second.map(|second| Few::from((Some(second), fourth)));
+        Some(Few::from((Some(first), third)))
+    })
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// TwoVisitor
+////////////////////////////////////////////////////////////////////////////////
+/// Deserializes the values of a `Two` variant, rejecting inputs which do not
+/// contain exactly two values.
+#[cfg(feature = "serde")]
+struct TwoVisitor<T>(std::marker::PhantomData<T>);
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::de::Visitor<'de> for TwoVisitor<T>
+    where T: Deserialize<'de>,
+{
+    type Value = (T, T);
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("exactly two values")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<(T, T), A::Error>
+        where A: serde::de::SeqAccess<'de>,
+    {
+        use serde::de::{ Error, IgnoredAny };
+
+        let a = seq.next_element()?
+            .ok_or_else(|| Error::invalid_length(0, &self))?;
+        let b = seq.next_element()?
+            .ok_or_else(|| Error::invalid_length(1, &self))?;
+
+        let mut len = 2;
+        while seq.next_element::<IgnoredAny>()?.is_some() {
+            len += 1;
+        }
+        if len > 2 {
+            return Err(Error::invalid_length(len, &self));
+        }
+        Ok((a, b))
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// coalesce_ranges
+////////////////////////////////////////////////////////////////////////////////
+/// Merges a collection of `Few`s interpreted as ranges into a minimal sorted
+/// set of disjoint ranges.
+///
+/// Ranges which overlap or share an endpoint are merged. A `One` is treated as
+/// a range containing a single value, and is merged into any range containing
+/// it. A `Zero` is an empty range, and is removed. Merged ranges with equal
+/// bounds are given as a `One`.
+pub fn coalesce_ranges<T>(ranges: &mut Vec<Few<T>>)
+    where T: Ord + Clone,
+{
+    let mut bounds: Vec<(T, T)> = ranges
+        .iter()
+        .filter_map(Few::min_max)
+        .map(|(lo, hi)| (lo.clone(), hi.clone()))
+        .collect();
+    bounds.sort();
+
+    let mut merged: Vec<(T, T)> = Vec::with_capacity(bounds.len());
+    for (lo, hi) in bounds {
+        match merged.last_mut() {
+            Some((_, last_hi)) if lo <= *last_hi => if hi > *last_hi {
+                *last_hi = hi;
+            },
+            _ => merged.push((lo, hi)),
+        }
+    }
+
+    ranges.clear();
+    ranges.extend(merged
+        .into_iter()
+        .map(|(lo, hi)| Few::from_bounds(lo, hi)));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// bounding_few
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the smallest range containing every value of the given iterator.
+///
+/// The range is given as a `Two` of the minimum and maximum values, a `One` if
+/// all of the values are equal, or a `Zero` if the iterator is empty.
+pub fn bounding_few<T, I>(iter: I) -> Few<T>
+    where
+        T: Ord,
+        I: IntoIterator<Item=T>,
+{
+    iter.into_iter().fold(Few::Zero, Few::extend_bounds)
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// flatten_all
+////////////////////////////////////////////////////////////////////////////////
+/// Collects the values of each `Few` of the given iterator, in order, into a
+/// single `Vec`.
+pub fn flatten_all<T, I>(iter: I) -> Vec<T>
+    where I: IntoIterator<Item=Few<T>>,
+{
+    let iter = iter.into_iter();
+    let mut values = Vec::with_capacity(iter.size_hint().0);
+    for few in iter {
+        values.extend(few);
+    }
+    values
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// coverage_points
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the sorted sweep-line events for a collection of `Few`s interpreted
+/// as ranges, suitable for computing how many ranges overlap at each point.
+///
+/// Each `Two` contributes a `+1` event at its lower bound and a `-1` event at
+/// its upper bound, where the `-1` applies just after the bound, so that ranges
+/// sharing an endpoint overlap there. Events are sorted by position, with `+1`
+/// events before `-1` events at the same position. `One` and `Zero` values
+/// contribute no events.
+pub fn coverage_points<T>(ranges: &[Few<T>]) -> Vec<(T, isize)>
+    where T: Ord + Clone,
+{
+    let mut events = Vec::with_capacity(ranges.len() * 2);
+    for (lo, hi) in ranges.iter().filter_map(Few::as_range) {
+        events.push((lo.clone(), 1));
+        events.push((hi.clone(), -1));
+    }
+    events.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+    events
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// merge_runs_by
+////////////////////////////////////////////////////////////////////////////////
+/// Merges consecutive `Few`s of the given collection using the given function,
+/// which returns the merged `Few` if two `Few`s can be merged, or `None`
+/// otherwise. Merging is repeated until no consecutive `Few`s can be merged.
+///
+/// Only consecutive `Few`s are compared, so the collection should be sorted
+/// such that mergeable `Few`s are adjacent.
+pub fn merge_runs_by<T, F>(ranges: &mut Vec<Few<T>>, adjacent: F)
+    where F: Fn(&Few<T>, &Few<T>) -> Option<Few<T>>,
+{
+    let mut merged: Vec<Few<T>> = Vec::with_capacity(ranges.len());
+    for mut current in ranges.drain(..) {
+        // Merging may make the result mergeable with the previous `Few`, so
+        // keep merging backwards until it can't be.
+        while let Some(prev) = merged.last() {
+            match (adjacent)(prev, &current) {
+                Some(few) => { let _ = merged.pop(); current = few; },
+                None      => break,
+            }
+        }
+        merged.push(current);
+    }
+    *ranges = merged;
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// max_overlap_depth
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the maximum number of ranges in the given collection which overlap
+/// at any single point.
+///
+/// As with [`coverage_points`], only `Two` values are counted as ranges, and
+/// ranges sharing an endpoint overlap there. `One` and `Zero` values are
+/// ignored.
+pub fn max_overlap_depth<T>(ranges: &[Few<T>]) -> usize
+    where T: Ord + Clone,
+{
+    let mut depth: isize = 0;
+    let mut max = 0;
+    for (_, delta) in coverage_points(ranges) {
+        depth += delta;
+        max = max.max(depth);
+    }
+    max as usize
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// filter_overlapping
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the `Few`s of the given collection which overlap the given query,
+/// interpreting each as a range.
+///
+/// As in [`Few::relation_to`], a `One` is treated as a range containing a
+/// single value. A `Zero` is an empty range, which overlaps nothing.
+pub fn filter_overlapping<T>(ranges: &[Few<T>], query: &Few<T>) -> Vec<Few<T>>
+    where T: Ord + Clone,
+{
+    if query.is_zero() { return Vec::new(); }
+    ranges
+        .iter()
+        .filter(|range| !range.is_zero() && !matches!(
+            range.relation_to(query),
+            RangeRelation::Before | RangeRelation::After))
+        .cloned()
+        .collect()
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// reduce_few
+////////////////////////////////////////////////////////////////////////////////
+/// Reduces the given `Few`s to a single `Few` by repeatedly applying the given
+/// function. Returns `Zero` if there are no `Few`s.
+pub fn reduce_few<T, I, F>(iter: I, combine: F) -> Few<T>
+    where
+        I: IntoIterator<Item=Few<T>>,
+        F: FnMut(Few<T>, Few<T>) -> Few<T>,
+{
+    iter.into_iter().reduce(combine).unwrap_or(Few::Zero)
 }
 
 
@@ -261,6 +2357,8 @@ struct ExitGuard;
 
 impl Drop for ExitGuard {
     fn drop(&mut self) {
-        panic!("`replace_with` closure unwind");
+        // The value behind the reference has been moved out, so unwinding past
+        // this point would expose it to a double drop.
+        std::process::abort();
     }
 }
@@ -0,0 +1,2158 @@
+////////////////////////////////////////////////////////////////////////////////
+// Few -- A generalization of `std::Option` allowing for up to two optional
+// values.
+////////////////////////////////////////////////////////////////////////////////
+// Copyright 2020 Skylor R. Schermer
+// This code is dual licenced using the MIT or Apache 2 license.
+// See licence-mit.md and licence-apache.md for details.
+////////////////////////////////////////////////////////////////////////////////
+//! Tests for `Few` methods and supporting functions.
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use few::*;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// chunk_by_key
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn chunk_by_key_runs() {
+    let items = [
+        (1, 'a'), (1, 'b'),
+        (2, 'c'),
+        (3, 'd'), (3, 'e'), (3, 'f'),
+        (1, 'g'),
+    ];
+
+    assert_eq!(chunk_by_key(&items, |item| item.0), vec![
+        Few::Two((1, 'a'), (1, 'b')),
+        Few::One((2, 'c')),
+        Few::Two((3, 'd'), (3, 'f')),
+        Few::One((1, 'g')),
+    ]);
+}
+
+#[test]
+fn chunk_by_key_empty() {
+    let items: [u8; 0] = [];
+    assert_eq!(chunk_by_key(&items, |item| *item), vec![]);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// map_with_neighbor
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn map_with_neighbor_two() {
+    let few = Few::Two(1, 5);
+    assert_eq!(few.map_with_neighbor(|v, n| (v, n.copied())),
+        Few::Two((1, Some(5)), (5, Some(1))));
+}
+
+#[test]
+fn map_with_neighbor_sees_original_first() {
+    let few = Few::Two(1, 5);
+    assert_eq!(few.map_with_neighbor(|v, n| v * 10 + n.copied().unwrap()),
+        Few::Two(15, 51));
+}
+
+#[test]
+fn map_with_neighbor_one_and_zero() {
+    assert_eq!(Few::One(1).map_with_neighbor(|v, n| (v, n.copied())),
+        Few::One((1, None)));
+    assert_eq!(Few::<i32>::Zero.map_with_neighbor(|v, _| v), Few::Zero);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// as_ptr
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn layout_size() {
+    assert_eq!(std::mem::size_of::<Few<u8>>(), 3);
+    assert_eq!(std::mem::size_of::<Few<u32>>(), 12);
+}
+
+#[test]
+fn as_ptr_one() {
+    let few = Few::One(4u8);
+    assert_eq!(unsafe { *few.as_ptr() }, 4);
+}
+
+#[test]
+fn as_ptr_two() {
+    let few = Few::Two(7u64, 9u64);
+    let ptr = few.as_ptr();
+    assert_eq!(unsafe { *ptr }, 7);
+    assert_eq!(unsafe { *ptr.add(1) }, 9);
+
+    let few = Few::Two(1u8, 2u8);
+    let slice = unsafe { std::slice::from_raw_parts(few.as_ptr(), 2) };
+    assert_eq!(slice, &[1, 2]);
+}
+
+#[test]
+fn as_mut_ptr_one() {
+    let mut few = Few::One(4u8);
+    unsafe { *few.as_mut_ptr() = 5; }
+    assert_eq!(few, Few::One(5));
+}
+
+#[test]
+fn as_mut_ptr_two() {
+    let mut few = Few::Two(String::from("a"), String::from("b"));
+    let ptr = few.as_mut_ptr();
+    unsafe { *ptr.add(1) = String::from("c"); }
+    assert_eq!(few, Few::Two("a".into(), "c".into()));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// combine_ref
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn combine_ref_two() {
+    assert_eq!(Few::Two(1, 5).combine_ref(|a, b| a + b), Some(6));
+}
+
+#[test]
+fn combine_ref_one_and_zero() {
+    assert_eq!(Few::One(1).combine_ref(|a, b| a + b), None);
+    assert_eq!(Few::<i32>::Zero.combine_ref(|a, b| a + b), None);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// fill_from
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn fill_from_zero() {
+    let mut iter = 1..10;
+    let mut few = Few::Zero;
+    assert_eq!(few.fill_from(&mut iter), 2);
+    assert_eq!(few, Few::Two(1, 2));
+    assert_eq!(iter.next(), Some(3));
+}
+
+#[test]
+fn fill_from_one() {
+    let mut iter = 1..10;
+    let mut few = Few::One(0);
+    assert_eq!(few.fill_from(&mut iter), 1);
+    assert_eq!(few, Few::Two(0, 1));
+    assert_eq!(iter.next(), Some(2));
+}
+
+#[test]
+fn fill_from_two() {
+    let mut iter = 1..10;
+    let mut few = Few::Two(0, 0);
+    assert_eq!(few.fill_from(&mut iter), 0);
+    assert_eq!(few, Few::Two(0, 0));
+    assert_eq!(iter.next(), Some(1));
+}
+
+#[test]
+fn fill_from_short_iterator() {
+    let mut few = Few::Zero;
+    assert_eq!(few.fill_from(vec![1]), 1);
+    assert_eq!(few, Few::One(1));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// symmetric_difference
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn symmetric_difference_overlapping() {
+    assert_eq!(Few::Two(1, 3).symmetric_difference(Few::Two(3, 5)),
+        vec![1, 5]);
+    assert_eq!(Few::Two(2, 1).symmetric_difference(Few::Two(1, 2)),
+        vec![]);
+}
+
+#[test]
+fn symmetric_difference_disjoint() {
+    assert_eq!(Few::Two(4, 1).symmetric_difference(Few::Two(2, 3)),
+        vec![1, 2, 3, 4]);
+    assert_eq!(Few::One(1).symmetric_difference(Few::Zero), vec![1]);
+}
+
+#[test]
+fn symmetric_difference_duplicates() {
+    assert_eq!(Few::Two(1, 1).symmetric_difference(Few::One(1)), vec![]);
+    assert_eq!(Few::Two(2, 2).symmetric_difference(Few::Zero), vec![2]);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// cast
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn cast_widening() {
+    assert_eq!(Few::Two(1u8, 2u8).cast::<u64>(), Few::Two(1u64, 2u64));
+}
+
+#[test]
+fn try_cast_success() {
+    assert_eq!(Few::Two(1u64, 2u64).try_cast::<u32>(),
+        Ok(Few::Two(1u32, 2u32)));
+    assert_eq!(Few::<u64>::Zero.try_cast::<u32>(), Ok(Few::Zero));
+}
+
+#[test]
+fn try_cast_overflow() {
+    assert!(Few::Two(1u64, u64::MAX).try_cast::<u32>().is_err());
+    assert!(Few::Two(u64::MAX, 1u64).try_cast::<u32>().is_err());
+    assert!(Few::One(-1i32).try_cast::<u32>().is_err());
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// interleave
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn interleave_equal_lengths() {
+    assert_eq!(Few::Two(1, 3).interleave(Few::Two(2, 4)), vec![1, 2, 3, 4]);
+    assert_eq!(Few::One(1).interleave(Few::One(2)), vec![1, 2]);
+    assert_eq!(Few::<i32>::Zero.interleave(Few::Zero), vec![]);
+}
+
+#[test]
+fn interleave_stops_at_first_missing_value() {
+    assert_eq!(Few::Two(1, 3).interleave(Few::One(2)), vec![1, 2, 3]);
+    assert_eq!(Few::Two(1, 3).interleave(Few::Zero), vec![1]);
+    assert_eq!(Few::One(1).interleave(Few::Two(2, 4)), vec![1, 2]);
+    assert_eq!(Few::Zero.interleave(Few::Two(2, 4)), vec![]);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// size_hint_logical
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn size_hint_logical_distinct() {
+    assert_eq!(Few::Two(1, 2).size_hint_logical(), (2, 2));
+}
+
+#[test]
+fn size_hint_logical_duplicate() {
+    assert_eq!(Few::Two(1, 1).size_hint_logical(), (1, 1));
+}
+
+#[test]
+fn size_hint_logical_one_and_zero() {
+    assert_eq!(Few::One(1).size_hint_logical(), (1, 1));
+    assert_eq!(Few::<i32>::Zero.size_hint_logical(), (0, 0));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// sort_ascending
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn sort_ascending_sorted() {
+    let mut few = Few::Two(1, 3);
+    few.sort_ascending();
+    assert_eq!(few, Few::Two(1, 3));
+}
+
+#[test]
+fn sort_ascending_reversed() {
+    let mut few = Few::Two(3, 1);
+    few.sort_ascending();
+    assert_eq!(few, Few::Two(1, 3));
+}
+
+#[test]
+fn sort_ascending_one() {
+    let mut few = Few::One(1);
+    few.sort_ascending();
+    assert_eq!(few, Few::One(1));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// every_other
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn every_other_full_blocks() {
+    assert_eq!(every_other(0..4).collect::<Vec<_>>(), vec![
+        Few::Two(0, 2), Few::Two(1, 3),
+    ]);
+    assert_eq!(every_other(0..8).collect::<Vec<_>>(), vec![
+        Few::Two(0, 2), Few::Two(1, 3),
+        Few::Two(4, 6), Few::Two(5, 7),
+    ]);
+}
+
+#[test]
+fn every_other_partial_block() {
+    assert_eq!(every_other(0..1).collect::<Vec<_>>(), vec![Few::One(0)]);
+    assert_eq!(every_other(0..2).collect::<Vec<_>>(), vec![
+        Few::One(0), Few::One(1),
+    ]);
+    assert_eq!(every_other(0..3).collect::<Vec<_>>(), vec![
+        Few::Two(0, 2), Few::One(1),
+    ]);
+    assert_eq!(every_other(0..5).collect::<Vec<_>>(), vec![
+        Few::Two(0, 2), Few::Two(1, 3),
+        Few::One(4),
+    ]);
+}
+
+#[test]
+fn every_other_empty() {
+    assert_eq!(every_other(0..0).count(), 0);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// as_cow_slice
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn as_cow_slice_borrowed() {
+    use std::borrow::Cow;
+
+    let few = Few::Two(String::from("a"), String::from("b"));
+    let slice = few.as_cow_slice();
+    assert!(matches!(slice, Cow::Borrowed(_)));
+    assert_eq!(&*slice, &["a".to_string(), "b".to_string()]);
+
+    let few = Few::One(1);
+    let slice = few.as_cow_slice();
+    assert!(matches!(slice, Cow::Borrowed(_)));
+    assert_eq!(&*slice, &[1]);
+
+    let few = Few::<i32>::Zero;
+    let slice = few.as_cow_slice();
+    assert!(matches!(slice, Cow::Borrowed(_)));
+    assert_eq!(&*slice, &[] as &[i32]);
+}
+
+#[test]
+fn as_cow_slice_zero_sized() {
+    assert_eq!(&*Few::Two((), ()).as_cow_slice(), &[(), ()]);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// reduce_in_place
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn reduce_in_place_two() {
+    let mut few = Few::Two(vec![1], vec![2, 3]);
+    few.reduce_in_place(|a, b| a.extend(b));
+    assert_eq!(few, Few::One(vec![1, 2, 3]));
+}
+
+#[test]
+fn reduce_in_place_one_and_zero() {
+    let mut few = Few::One(5);
+    few.reduce_in_place(|a, b| *a += b);
+    assert_eq!(few, Few::One(5));
+
+    let mut few = Few::<i32>::Zero;
+    few.reduce_in_place(|a, b| *a += b);
+    assert_eq!(few, Few::Zero);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// same_shape
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn same_shape_matching() {
+    assert!(Few::<i32>::Zero.same_shape(&Few::<&str>::Zero));
+    assert!(Few::One(1).same_shape(&Few::One('c')));
+    assert!(Few::Two(1, 2).same_shape(&Few::Two("a", "b")));
+}
+
+#[test]
+fn same_shape_mismatched() {
+    assert!(!Few::<u8>::Zero.same_shape(&Few::One('c')));
+    assert!(!Few::One(1).same_shape(&Few::Two('c', 'd')));
+    assert!(!Few::Two(1, 2).same_shape(&Few::<String>::Zero));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// try_map_indexed
+////////////////////////////////////////////////////////////////////////////////
+fn checked_index(idx: usize, v: i32) -> Result<i32, usize> {
+    if v < 0 { Err(idx) } else { Ok(v * 10 + idx as i32) }
+}
+
+#[test]
+fn try_map_indexed_success() {
+    assert_eq!(Few::Two(1, 2).try_map_indexed(checked_index),
+        Ok(Few::Two(10, 21)));
+    assert_eq!(Few::One(1).try_map_indexed(checked_index), Ok(Few::One(10)));
+    assert_eq!(Few::Zero.try_map_indexed(checked_index), Ok(Few::Zero));
+}
+
+#[test]
+fn try_map_indexed_error_at_first() {
+    assert_eq!(Few::Two(-1, 2).try_map_indexed(checked_index), Err(0));
+    assert_eq!(Few::Two(-1, -2).try_map_indexed(checked_index), Err(0));
+    assert_eq!(Few::One(-1).try_map_indexed(checked_index), Err(0));
+}
+
+#[test]
+fn try_map_indexed_error_at_second() {
+    assert_eq!(Few::Two(1, -2).try_map_indexed(checked_index), Err(1));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// peek
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn peek_matches_next() {
+    let mut few = Few::Two(1, 2);
+    assert_eq!(few.peek(), Some(&1));
+    assert_eq!(few.peek(), Some(&1));
+    assert_eq!(few, Few::Two(1, 2));
+    assert_eq!(few.next(), Some(1));
+
+    assert_eq!(few.peek(), Some(&2));
+    assert_eq!(few.next(), Some(2));
+
+    assert_eq!(few.peek(), None);
+    assert_eq!(few.next(), None);
+}
+
+#[test]
+fn peek_back_matches_next_back() {
+    let mut few = Few::Two(1, 2);
+    assert_eq!(few.peek_back(), Some(&2));
+    assert_eq!(few.peek_back(), Some(&2));
+    assert_eq!(few, Few::Two(1, 2));
+    assert_eq!(few.next_back(), Some(2));
+
+    assert_eq!(few.peek_back(), Some(&1));
+    assert_eq!(few.next_back(), Some(1));
+
+    assert_eq!(few.peek_back(), None);
+    assert_eq!(few.next_back(), None);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// drain
+////////////////////////////////////////////////////////////////////////////////
+/// A value which counts the number of times it has been dropped.
+#[derive(Debug)]
+struct DropCounter(std::rc::Rc<std::cell::Cell<usize>>);
+
+impl Drop for DropCounter {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn drain_full() {
+    let mut few = Few::Two(1, 2);
+    {
+        let mut drain = few.drain();
+        assert_eq!(drain.len(), 2);
+        assert_eq!(drain.next(), Some(1));
+        assert_eq!(drain.len(), 1);
+        assert_eq!(drain.next(), Some(2));
+        assert_eq!(drain.next(), None);
+    }
+    assert_eq!(few, Few::Zero);
+}
+
+#[test]
+fn drain_partial_then_drop() {
+    let drops = std::rc::Rc::new(std::cell::Cell::new(0));
+    let mut few = Few::Two(
+        DropCounter(drops.clone()),
+        DropCounter(drops.clone()));
+
+    let mut drain = few.drain();
+    let last = drain.next_back();
+    assert!(last.is_some());
+    drop(drain);
+    assert_eq!(drops.get(), 1);
+    assert!(few.is_zero());
+
+    drop(last);
+    assert_eq!(drops.get(), 2);
+}
+
+#[test]
+fn drain_forget() {
+    let mut few = Few::Two(String::from("a"), String::from("b"));
+    std::mem::forget(few.drain());
+    assert_eq!(few, Few::Zero);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// NonEmptyFew
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn into_nonempty() {
+    assert_eq!(Few::<i32>::Zero.into_nonempty(), None);
+    assert_eq!(Few::One(1).into_nonempty(), Some(NonEmptyFew::One(1)));
+    assert_eq!(Few::Two(1, 2).into_nonempty(), Some(NonEmptyFew::Two(1, 2)));
+}
+
+#[test]
+fn nonempty_into_few() {
+    assert_eq!(Few::from(NonEmptyFew::One(1)), Few::One(1));
+    assert_eq!(Few::from(NonEmptyFew::Two(1, 2)), Few::Two(1, 2));
+}
+
+#[test]
+fn nonempty_round_trip() {
+    for few in [Few::One(1), Few::Two(1, 2)] {
+        assert_eq!(few.into_nonempty().map(Few::from), Some(few));
+    }
+}
+
+#[test]
+fn nonempty_first() {
+    assert_eq!(NonEmptyFew::One(1).first(), &1);
+    assert_eq!(NonEmptyFew::Two(1, 2).first(), &1);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// from_iter_exact
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn from_iter_exact_exact() {
+    assert_eq!(Few::from_iter_exact(Vec::<i32>::new(), 0), Ok(Few::Zero));
+    assert_eq!(Few::from_iter_exact(vec![1], 1), Ok(Few::One(1)));
+    assert_eq!(Few::from_iter_exact(vec![1, 2], 2), Ok(Few::Two(1, 2)));
+}
+
+#[test]
+fn from_iter_exact_under() {
+    let err = Few::from_iter_exact(vec![1], 2).unwrap_err();
+    assert_eq!(err, LenMismatch {
+        expected: 2,
+        collected: Few::One(1),
+        extra: None,
+    });
+    assert_eq!(err.to_string(), "expected 2 values, found 1");
+}
+
+#[test]
+fn from_iter_exact_over() {
+    let err = Few::from_iter_exact(vec![1, 2, 3, 4], 2).unwrap_err();
+    assert_eq!(err, LenMismatch {
+        expected: 2,
+        collected: Few::Two(1, 2),
+        extra: Some(3),
+    });
+    assert_eq!(err.to_string(), "expected 2 values, found more");
+
+    let err = Few::from_iter_exact(vec![1], 0).unwrap_err();
+    assert_eq!(err, LenMismatch {
+        expected: 0,
+        collected: Few::Zero,
+        extra: Some(1),
+    });
+}
+
+#[test]
+fn from_iter_exact_over_capacity() {
+    let err = Few::from_iter_exact(vec![1, 2, 3], 3).unwrap_err();
+    assert_eq!(err, LenMismatch {
+        expected: 3,
+        collected: Few::Two(1, 2),
+        extra: None,
+    });
+    assert_eq!(err.to_string(),
+        "expected 3 values, which exceeds the capacity of `Few`");
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// map_collect
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn map_collect_vec() {
+    let values: Vec<String> = Few::Two(1, 2).map_collect(|v| v.to_string());
+    assert_eq!(values, vec!["1", "2"]);
+}
+
+#[test]
+fn map_collect_hash_set() {
+    use std::collections::HashSet;
+
+    let values: HashSet<i32> = Few::Two(1, -1).map_collect(i32::abs);
+    assert_eq!(values, [1].iter().copied().collect());
+
+    let values: HashSet<i32> = Few::Two(1, -2).map_collect(i32::abs);
+    assert_eq!(values, [1, 2].iter().copied().collect());
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// iter_copied
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn iter_copied_leaves_original() {
+    let few = Few::Two(1, 2);
+    assert_eq!(few.iter_copied().collect::<Vec<_>>(), vec![1, 2]);
+    assert_eq!(few, Few::Two(1, 2));
+}
+
+#[test]
+fn iter_cloned_leaves_original() {
+    let few = Few::Two(String::from("a"), String::from("b"));
+    assert_eq!(few.iter_cloned().collect::<Vec<_>>(), vec!["a", "b"]);
+    assert_eq!(few, Few::Two("a".into(), "b".into()));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// map_ok
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn map_ok_mixed() {
+    let few: Few<Result<i32, &str>> = Few::Two(Ok(1), Err("e"));
+    assert_eq!(few.map_ok(|v| v * 2), Few::Two(Ok(2), Err("e")));
+
+    let few: Few<Result<i32, &str>> = Few::Two(Err("e"), Ok(3));
+    assert_eq!(few.map_ok(|v| v.to_string()),
+        Few::Two(Err("e"), Ok("3".to_string())));
+}
+
+#[test]
+fn map_ok_all_ok_and_all_err() {
+    let few: Few<Result<i32, &str>> = Few::Two(Ok(1), Ok(2));
+    assert_eq!(few.map_ok(|v| v + 1), Few::Two(Ok(2), Ok(3)));
+
+    let few: Few<Result<i32, &str>> = Few::One(Err("e"));
+    assert_eq!(few.map_ok(|v| v + 1), Few::One(Err("e")));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// one_or_two
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+#[cfg(feature = "either")]
+fn one_or_two() {
+    use either::Either;
+
+    assert_eq!(Few::<i32>::Zero.one_or_two(), None);
+    assert_eq!(Few::One(1).one_or_two(), Some(Either::Left(1)));
+    assert_eq!(Few::Two(1, 2).one_or_two(), Some(Either::Right((1, 2))));
+}
+
+#[test]
+#[cfg(feature = "either")]
+fn from_either() {
+    use either::Either;
+
+    assert_eq!(Few::from(Either::<i32, (i32, i32)>::Left(1)), Few::One(1));
+    assert_eq!(Few::from(Either::<i32, (i32, i32)>::Right((1, 2))),
+        Few::Two(1, 2));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// to_bits
+////////////////////////////////////////////////////////////////////////////////
+const ALL_BOOL_FEWS: [Few<bool>; 7] = [
+    Few::Zero,
+    Few::One(false),
+    Few::One(true),
+    Few::Two(false, false),
+    Few::Two(false, true),
+    Few::Two(true, false),
+    Few::Two(true, true),
+];
+
+#[test]
+fn bits_round_trip() {
+    for few in ALL_BOOL_FEWS.iter() {
+        assert_eq!(Few::from_bits(few.to_bits()), Some(*few));
+    }
+}
+
+#[test]
+fn bits_distinct() {
+    let bits: std::collections::HashSet<u8> = ALL_BOOL_FEWS
+        .iter()
+        .map(|few| few.to_bits())
+        .collect();
+    assert_eq!(bits.len(), ALL_BOOL_FEWS.len());
+    assert_eq!(Few::Two(true, false).to_bits(), 0b0110);
+}
+
+#[test]
+fn from_bits_invalid() {
+    assert_eq!(Few::from_bits(0b0011), None);
+    assert_eq!(Few::from_bits(0b1001), None);
+    assert_eq!(Few::from_bits(0b0100), None);
+    assert_eq!(Few::from_bits(0b1_0000), None);
+
+    let valid = (0..=u8::MAX)
+        .filter(|bits| Few::from_bits(*bits).is_some())
+        .count();
+    assert_eq!(valid, ALL_BOOL_FEWS.len());
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// replace_contents
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn replace_contents_each_len() {
+    let mut few = Few::Two(9, 9);
+    assert_eq!(few.replace_contents(vec![]), Ok(()));
+    assert_eq!(few, Few::Zero);
+    assert_eq!(few.replace_contents(vec![1]), Ok(()));
+    assert_eq!(few, Few::One(1));
+    assert_eq!(few.replace_contents(vec![1, 2]), Ok(()));
+    assert_eq!(few, Few::Two(1, 2));
+}
+
+#[test]
+fn replace_contents_overflow() {
+    let mut few = Few::One(9);
+    let err = few.replace_contents(vec![3, 4, 5, 6]).unwrap_err();
+    assert_eq!(err, TooManyError { collected: Few::Two(3, 4), overflow: 5 });
+    assert_eq!(few, Few::Zero);
+    assert_eq!(
+        err.to_string(),
+        "too many values for `Few`, expected at most 2");
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// debug_compact
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn debug_compact_each_variant() {
+    assert_eq!(format!("{:?}", Few::<i32>::Zero.debug_compact()), "[]");
+    assert_eq!(format!("{:?}", Few::One("a").debug_compact()), "[\"a\"]");
+    assert_eq!(format!("{:?}", Few::Two(1, 2).debug_compact()), "[1, 2]");
+}
+
+#[test]
+fn debug_compact_alternate() {
+    assert_eq!(
+        format!("{:#?}", Few::Two(1, 2).debug_compact()),
+        "[\n    1,\n    2,\n]");
+    assert_eq!(format!("{:?}", Few::Two(1, 2)), "Two(1, 2)");
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// try_zip
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn try_zip_same_variant() {
+    assert_eq!(Few::<i32>::Zero.try_zip(Few::<char>::Zero), Ok(Few::Zero));
+    assert_eq!(Few::One(1).try_zip(Few::One('a')), Ok(Few::One((1, 'a'))));
+    assert_eq!(
+        Few::Two(1, 2).try_zip(Few::Two('a', 'b')),
+        Ok(Few::Two((1, 'a'), (2, 'b'))));
+}
+
+#[test]
+fn try_zip_mismatch_keeps_inputs() {
+    assert_eq!(
+        Few::Two(1, 2).try_zip(Few::One('a')),
+        Err((Few::Two(1, 2), Few::One('a'))));
+    assert_eq!(
+        Few::<i32>::Zero.try_zip(Few::Two('a', 'b')),
+        Err((Few::Zero, Few::Two('a', 'b'))));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// midpoint
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn midpoint_each_variant() {
+    assert_eq!(Few::<u32>::Zero.midpoint(), None);
+    assert_eq!(Few::One(7u32).midpoint(), Some(7));
+    assert_eq!(Few::Two(2, 6).midpoint(), Some(4));
+}
+
+#[test]
+fn midpoint_rounds_toward_lower() {
+    assert_eq!(Few::Two(2, 5).midpoint(), Some(3));
+    assert_eq!(Few::Two(5, 2).midpoint(), Some(3));
+    assert_eq!(Few::Two(-5, -2).midpoint(), Some(-4));
+}
+
+#[test]
+fn midpoint_float() {
+    assert_eq!(Few::Two(1.0f64, 2.0).midpoint(), Some(1.5));
+    assert_eq!(Few::Two(2.0f64, 1.0).midpoint(), Some(1.5));
+    assert_eq!(Few::Two(-1.0f32, 1.0).midpoint(), Some(0.0));
+    assert_eq!(Few::One(0.25f64).midpoint(), Some(0.25));
+}
+
+#[test]
+fn midpoint_no_overflow() {
+    assert_eq!(Few::Two(200u8, 100u8).midpoint(), Some(150));
+    assert_eq!(Few::Two(u8::MAX, u8::MAX - 1).midpoint(), Some(254));
+    let (lo, hi) = (i16::MAX - 2, i16::MAX);
+    assert_eq!(Few::Two(hi, lo).midpoint(), Some(hi - 1));
+    assert_eq!(Few::Two(u64::MAX, 0).midpoint(), Some(u64::MAX / 2));
+}
+
+#[test]
+#[should_panic(expected = "attempt to subtract with overflow")]
+fn midpoint_difference_overflow() {
+    let _ = Few::Two(i32::MIN, i32::MAX).midpoint();
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// insert_sorted
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn insert_sorted_fills() {
+    let mut few = Few::Zero;
+    assert_eq!(few.insert_sorted(5), None);
+    assert_eq!(few, Few::One(5));
+    assert_eq!(few.insert_sorted(3), None);
+    assert_eq!(few, Few::Two(3, 5));
+
+    let mut few = Few::One(5);
+    assert_eq!(few.insert_sorted(7), None);
+    assert_eq!(few, Few::Two(5, 7));
+}
+
+#[test]
+fn insert_sorted_evicts_largest() {
+    let mut few = Few::Two(3, 5);
+    assert_eq!(few.insert_sorted(9), Some(9));
+    assert_eq!(few, Few::Two(3, 5));
+    assert_eq!(few.insert_sorted(5), Some(5));
+    assert_eq!(few, Few::Two(3, 5));
+    assert_eq!(few.insert_sorted(4), Some(5));
+    assert_eq!(few, Few::Two(3, 4));
+    assert_eq!(few.insert_sorted(1), Some(4));
+    assert_eq!(few, Few::Two(1, 3));
+}
+
+#[test]
+fn insert_sorted_unsorted_two() {
+    let mut few = Few::Two(8, 2);
+    assert_eq!(few.insert_sorted(5), Some(8));
+    assert_eq!(few, Few::Two(2, 5));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// bounding_few
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn bounding_few_empty() {
+    assert_eq!(bounding_few(Vec::<i32>::new()), Few::Zero);
+}
+
+#[test]
+fn bounding_few_single_value() {
+    assert_eq!(bounding_few(vec![4]), Few::One(4));
+    assert_eq!(bounding_few(vec![4, 4, 4]), Few::One(4));
+}
+
+#[test]
+fn bounding_few_many_values() {
+    assert_eq!(bounding_few(vec![4, 9, -2, 3, 9]), Few::Two(-2, 9));
+    assert_eq!(bounding_few(vec![9, 4]), Few::Two(4, 9));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// split_while
+////////////////////////////////////////////////////////////////////////////////
+fn less_than_five(v: &i32) -> bool { *v < 5 }
+
+#[test]
+fn split_while_two() {
+    assert_eq!(
+        Few::Two(1, 2).split_while(less_than_five),
+        (Few::Two(1, 2), Few::Zero));
+    assert_eq!(
+        Few::Two(1, 7).split_while(less_than_five),
+        (Few::One(1), Few::One(7)));
+    assert_eq!(
+        Few::Two(7, 1).split_while(less_than_five),
+        (Few::Zero, Few::Two(7, 1)));
+    assert_eq!(
+        Few::Two(8, 9).split_while(less_than_five),
+        (Few::Zero, Few::Two(8, 9)));
+}
+
+#[test]
+fn split_while_one_and_zero() {
+    assert_eq!(
+        Few::One(1).split_while(less_than_five),
+        (Few::One(1), Few::Zero));
+    assert_eq!(
+        Few::One(9).split_while(less_than_five),
+        (Few::Zero, Few::One(9)));
+    assert_eq!(
+        Few::Zero.split_while(less_than_five),
+        (Few::Zero, Few::Zero));
+}
+
+#[test]
+fn split_while_stops_at_first_failure() {
+    let mut calls = 0;
+    let _ = Few::Two(7, 1).split_while(|v| { calls += 1; *v < 5 });
+    assert_eq!(calls, 1);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// map_owned_in_place
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn map_owned_in_place_preserves_variant() {
+    let mut few = Few::<String>::Zero;
+    few.map_owned_in_place(|s| s + "!");
+    assert_eq!(few, Few::Zero);
+
+    let mut few = Few::One(1);
+    few.map_owned_in_place(|v| v + 1);
+    assert_eq!(few, Few::One(2));
+
+    let mut few = Few::Two(String::from("a"), String::from("b"));
+    few.map_owned_in_place(|s| s + "!");
+    assert_eq!(few, Few::Two("a!".into(), "b!".into()));
+}
+
+#[cfg(unix)]
+#[test]
+fn map_owned_in_place_panic_aborts() {
+    use std::os::unix::process::ExitStatusExt;
+    const CHILD_VAR: &str = "FEW_MAP_OWNED_IN_PLACE_CHILD";
+
+    if std::env::var_os(CHILD_VAR).is_some() {
+        let mut few = Few::One(String::from("x"));
+        few.map_owned_in_place(|_| panic!("replacement failed"));
+        return;
+    }
+
+    let output = std::process::Command::new(std::env::current_exe().unwrap())
+        .args(["map_owned_in_place_panic_aborts", "--exact", "--nocapture"])
+        .env(CHILD_VAR, "1")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.signal(), Some(6));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// eq_with
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn eq_with_different_types() {
+    let few = Few::Two(String::from("a"), String::from("b"));
+    assert!(few.eq_with(&Few::Two("a", "b"), |a, b| a == b));
+    assert!(!few.eq_with(&Few::Two("a", "c"), |a, b| a == b));
+    assert!(Few::<String>::Zero.eq_with(&Few::<&str>::Zero, |a, b| a == b));
+}
+
+#[test]
+fn eq_with_shape_mismatch() {
+    let mut calls = 0;
+    let eq = Few::Two(1, 2).eq_with(&Few::One(1), |a, b| {
+        calls += 1;
+        a == b
+    });
+    assert!(!eq);
+    assert_eq!(calls, 0);
+    assert!(!Few::Zero.eq_with(&Few::One(1), |a: &i32, b| a == b));
+}
+
+#[test]
+fn eq_with_tolerance() {
+    let close = |a: &f64, b: &f64| (a - b).abs() < 0.01;
+    let few = Few::Two(1.0, 2.0);
+    assert!(few.eq_with(&Few::Two(1.0001, 1.9999), close));
+    assert!(!few.eq_with(&Few::Two(1.0, 2.1), close));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// approx
+////////////////////////////////////////////////////////////////////////////////
+#[cfg(feature = "approx")]
+#[test]
+fn approx_abs_diff_eq() {
+    use approx::AbsDiffEq as _;
+    let few = Few::Two(1.0f64, 2.0);
+    let near = Few::Two(1.0 + 1e-12, 2.0 - 1e-12);
+    assert_ne!(few, near);
+    assert!(few.abs_diff_eq(&near, 1e-9));
+    assert!(!few.abs_diff_eq(&Few::Two(1.0, 2.1), 1e-9));
+    approx::assert_abs_diff_eq!(few, near, epsilon = 1e-9);
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn approx_relative_eq() {
+    let few = Few::Two(1.0f64, 2.0);
+    let near = Few::Two(1.0 + 1e-12, 2.0 - 1e-12);
+    approx::assert_relative_eq!(few, near, max_relative = 1e-9);
+    approx::assert_relative_ne!(few, Few::Two(1.0, 2.1));
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn approx_shape_mismatch() {
+    let few = Few::Two(1.0f64, 2.0);
+    assert!(approx::abs_diff_ne!(few, Few::One(1.0), epsilon = 1.0));
+    assert!(approx::relative_ne!(Few::<f64>::Zero, Few::One(1.0)));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// flatten_all
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn flatten_all_mixed() {
+    let fews = vec![Few::Two(1, 2), Few::Zero, Few::One(3), Few::Two(4, 5)];
+    assert_eq!(flatten_all(fews), vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn flatten_all_empty() {
+    assert!(flatten_all(Vec::<Few<i32>>::new()).is_empty());
+    assert!(flatten_all(vec![Few::<i32>::Zero, Few::Zero]).is_empty());
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// at
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn at_first() {
+    assert_eq!(Few::<i32>::Zero.at(First), None);
+    assert_eq!(Few::One(1).at(First), Some(&1));
+    assert_eq!(Few::Two(1, 2).at(First), Some(&1));
+}
+
+#[test]
+fn at_second() {
+    assert_eq!(Few::<i32>::Zero.at(Second), None);
+    assert_eq!(Few::One(1).at(Second), None);
+    assert_eq!(Few::Two(1, 2).at(Second), Some(&2));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// try_fold_ref
+////////////////////////////////////////////////////////////////////////////////
+fn append_digit(acc: i32, v: &i32) -> Result<i32, i32> {
+    if *v < 0 { Err(*v) } else { Ok(acc * 10 + v) }
+}
+
+#[test]
+fn try_fold_ref_success() {
+    let few = Few::Two(1, 2);
+    assert_eq!(few.try_fold_ref(0, append_digit), Ok(12));
+    assert_eq!(few, Few::Two(1, 2));
+    assert_eq!(Few::One(3).try_fold_ref(1, append_digit), Ok(13));
+    assert_eq!(Few::Zero.try_fold_ref(5, append_digit), Ok(5));
+}
+
+#[test]
+fn try_fold_ref_short_circuits() {
+    assert_eq!(Few::Two(1, -2).try_fold_ref(0, append_digit), Err(-2));
+
+    let mut calls = 0;
+    let result = Few::Two(-1, -2).try_fold_ref(0, |acc, v| {
+        calls += 1;
+        append_digit(acc, v)
+    });
+    assert_eq!(result, Err(-1));
+    assert_eq!(calls, 1);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// with_offset
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn with_offset_each_variant() {
+    assert_eq!(Few::<char>::Zero.with_offset(10), Few::Zero);
+    assert_eq!(Few::One('a').with_offset(3), Few::One((3, 'a')));
+    assert_eq!(
+        Few::Two('a', 'b').with_offset(10),
+        Few::Two((10, 'a'), (11, 'b')));
+}
+
+#[test]
+fn with_offset_zero_base() {
+    assert_eq!(
+        Few::Two('a', 'b').with_offset(0),
+        Few::Two((0, 'a'), (1, 'b')));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// clear
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn clear_each_variant() {
+    let mut few = Few::Two(1, 2);
+    few.clear();
+    assert_eq!(few, Few::Zero);
+    let mut few = Few::One(1);
+    few.clear();
+    assert_eq!(few, Few::Zero);
+    let mut few = Few::<i32>::Zero;
+    few.clear();
+    assert_eq!(few, Few::Zero);
+}
+
+#[test]
+fn clear_drops_values() {
+    let drops = std::rc::Rc::new(std::cell::Cell::new(0));
+    let mut few = Few::Two(
+        DropCounter(drops.clone()),
+        DropCounter(drops.clone()));
+    few.clear();
+    assert!(few.is_zero());
+    assert_eq!(drops.get(), 2);
+    drop(few);
+    assert_eq!(drops.get(), 2);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// truncate
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn truncate_each_len() {
+    let cases = [
+        (Few::<i32>::Zero, [Few::Zero, Few::Zero, Few::Zero, Few::Zero]),
+        (Few::One(1), [Few::Zero, Few::One(1), Few::One(1), Few::One(1)]),
+        (Few::Two(1, 2), [
+            Few::Zero, Few::One(1), Few::Two(1, 2), Few::Two(1, 2),
+        ]),
+    ];
+    for (few, expected) in cases.iter() {
+        for (len, expected) in expected.iter().enumerate() {
+            let mut truncated = *few;
+            truncated.truncate(len);
+            assert_eq!(truncated, *expected, "{:?}.truncate({})", few, len);
+        }
+    }
+}
+
+#[test]
+fn truncate_drops_back() {
+    let front = std::rc::Rc::new(std::cell::Cell::new(0));
+    let back = std::rc::Rc::new(std::cell::Cell::new(0));
+    let mut few = Few::Two(
+        DropCounter(front.clone()),
+        DropCounter(back.clone()));
+    few.truncate(1);
+    assert!(few.is_one());
+    assert_eq!((front.get(), back.get()), (0, 1));
+    few.truncate(0);
+    assert_eq!((front.get(), back.get()), (1, 1));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// resize_with
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn resize_with_grow() {
+    let mut next = 0;
+    let mut gen = || { next += 1; next };
+    let mut few = Few::Zero;
+    few.resize_with(1, &mut gen);
+    assert_eq!(few, Few::One(1));
+    few.resize_with(2, &mut gen);
+    assert_eq!(few, Few::Two(1, 2));
+
+    let mut few = Few::Zero;
+    few.resize_with(2, &mut gen);
+    assert_eq!(few, Few::Two(3, 4));
+}
+
+#[test]
+fn resize_with_shrink() {
+    let mut calls = 0;
+    let mut few = Few::Two(1, 2);
+    few.resize_with(1, || { calls += 1; 0 });
+    assert_eq!(few, Few::One(1));
+    few.resize_with(1, || { calls += 1; 0 });
+    assert_eq!(few, Few::One(1));
+    few.resize_with(0, || { calls += 1; 0 });
+    assert_eq!(few, Few::Zero);
+    assert_eq!(calls, 0);
+}
+
+#[test]
+#[should_panic(expected = "new length 3 exceeds the capacity of `Few`")]
+fn resize_with_over_capacity() {
+    let mut few = Few::One(1);
+    few.resize_with(3, || 0);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// contains_all
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn contains_all_values() {
+    let few = Few::Two(1, 2);
+    assert!(few.contains_all(&[2, 1]));
+    assert!(few.contains_all(&[1, 1]));
+    assert!(!few.contains_all(&[1, 3]));
+    assert!(!Few::Zero.contains_all(&[1]));
+}
+
+#[test]
+fn contains_all_empty() {
+    assert!(Few::Two(1, 2).contains_all::<i32>(&[]));
+    assert!(Few::<i32>::Zero.contains_all::<i32>(&[]));
+}
+
+#[test]
+fn contains_any_values() {
+    let few = Few::Two(1, 2);
+    assert!(few.contains_any(&[3, 2]));
+    assert!(!few.contains_any(&[3, 4]));
+    assert!(Few::One(1).contains_any(&[1]));
+    assert!(!Few::Zero.contains_any(&[1]));
+}
+
+#[test]
+fn contains_any_empty() {
+    assert!(!Few::Two(1, 2).contains_any::<i32>(&[]));
+    assert!(!Few::<i32>::Zero.contains_any::<i32>(&[]));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// into_array_padded
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn into_array_padded_each_variant() {
+    assert_eq!(Few::Zero.into_array_padded(0), [0, 0]);
+    assert_eq!(Few::One(1).into_array_padded(0), [1, 0]);
+    assert_eq!(Few::Two(1, 2).into_array_padded(0), [1, 2]);
+}
+
+#[test]
+fn into_array_padded_clones_fill() {
+    let fill = String::from("-");
+    assert_eq!(
+        Few::<String>::Zero.into_array_padded(fill.clone()),
+        [fill.clone(), fill.clone()]);
+    assert_eq!(
+        Few::One(String::from("a")).into_array_padded(fill.clone()),
+        [String::from("a"), fill]);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// map_with
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn map_with_same_variant() {
+    assert_eq!(
+        Few::Two(1, 2).map_with(Few::Two(10, 20), |v, c| (v, c)),
+        Few::Two((1, Some(10)), (2, Some(20))));
+    assert_eq!(
+        Few::One(1).map_with(Few::One('a'), |v, c| (v, c)),
+        Few::One((1, Some('a'))));
+}
+
+#[test]
+fn map_with_short_context() {
+    assert_eq!(
+        Few::Two(1, 2).map_with(Few::One(10), |v, c| (v, c)),
+        Few::Two((1, Some(10)), (2, None)));
+    assert_eq!(
+        Few::Two(1, 2).map_with(Few::<i32>::Zero, |v, c| (v, c)),
+        Few::Two((1, None), (2, None)));
+}
+
+#[test]
+fn map_with_long_context() {
+    assert_eq!(
+        Few::One(1).map_with(Few::Two(10, 20), |v, c| (v, c)),
+        Few::One((1, Some(10))));
+    assert_eq!(
+        Few::<i32>::Zero.map_with(Few::Two(10, 20), |v, c| (v, c)),
+        Few::Zero);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// pair_mut
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn pair_mut_two() {
+    let mut few = Few::Two(1, 2);
+    if let Some((a, b)) = few.pair_mut() {
+        std::mem::swap(a, b);
+    }
+    assert_eq!(few, Few::Two(2, 1));
+}
+
+#[test]
+fn pair_mut_one_and_zero() {
+    assert_eq!(Few::One(1).pair_mut(), None);
+    assert_eq!(Few::<i32>::Zero.pair_mut(), None);
+}
+
+#[test]
+fn adjust_pair_two() {
+    let mut few = Few::Two(1, 2);
+    assert!(few.adjust_pair(|a, b| { *a += 10; *b *= 3; }));
+    assert_eq!(few, Few::Two(11, 6));
+}
+
+#[test]
+fn adjust_pair_one_and_zero() {
+    let mut calls = 0;
+    let mut few = Few::One(1);
+    assert!(!few.adjust_pair(|_, _| calls += 1));
+    assert_eq!(few, Few::One(1));
+    let mut few = Few::<i32>::Zero;
+    assert!(!few.adjust_pair(|_, _| calls += 1));
+    assert_eq!(calls, 0);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// iter_present
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn iter_present_skips_none() {
+    let few = Few::Two(None, Some(2));
+    assert_eq!(few.iter_present().collect::<Vec<_>>(), vec![&2]);
+    let few = Few::Two(Some(1), None);
+    assert_eq!(few.iter_present().collect::<Vec<_>>(), vec![&1]);
+    let few = Few::<Option<i32>>::Two(None, None);
+    assert_eq!(few.iter_present().count(), 0);
+}
+
+#[test]
+fn iter_present_all_some() {
+    let few = Few::Two(Some(1), Some(2));
+    assert_eq!(few.iter_present().collect::<Vec<_>>(), vec![&1, &2]);
+    assert_eq!(Few::One(Some(1)).iter_present().count(), 1);
+    assert_eq!(Few::<Option<i32>>::Zero.iter_present().count(), 0);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// zip_eq
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn zip_eq_same_variant() {
+    assert_eq!(Few::<i32>::Zero.zip_eq(Few::<char>::Zero), Few::Zero);
+    assert_eq!(Few::One(1).zip_eq(Few::One('a')), Few::One((1, 'a')));
+    assert_eq!(
+        Few::Two(1, 2).zip_eq(Few::Two('a', 'b')),
+        Few::Two((1, 'a'), (2, 'b')));
+}
+
+#[test]
+#[should_panic(
+    expected = "`zip_eq` called on `Few`s of different lengths: 2 and 1")]
+fn zip_eq_mismatch() {
+    let _ = Few::Two(1, 2).zip_eq(Few::One('a'));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// to_pair_map
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn to_pair_map_each_variant() {
+    let describe = |v: Option<i32>| v.map_or(-1, |v| v * 10);
+    assert_eq!(Few::Zero.to_pair_map(describe), (-1, -1));
+    assert_eq!(Few::One(1).to_pair_map(describe), (10, -1));
+    assert_eq!(Few::Two(1, 2).to_pair_map(describe), (10, 20));
+}
+
+#[test]
+fn to_pair_map_call_order() {
+    let mut calls = Vec::new();
+    let _ = Few::One('a').to_pair_map(|v| calls.push(v));
+    let _ = Few::Two('b', 'c').to_pair_map(|v| calls.push(v));
+    assert_eq!(calls, vec![Some('a'), None, Some('b'), Some('c')]);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// into_first_and_rest
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn into_first_and_rest_each_variant() {
+    assert_eq!(Few::<i32>::Zero.into_first_and_rest(), (None, None));
+    assert_eq!(Few::One(1).into_first_and_rest(), (Some(1), None));
+    assert_eq!(Few::Two(1, 2).into_first_and_rest(), (Some(1), Some(2)));
+}
+
+#[test]
+fn from_first_and_rest_round_trip() {
+    for few in [Few::Zero, Few::One(1), Few::Two(1, 2)].iter() {
+        let (first, rest) = few.into_first_and_rest();
+        assert_eq!(Few::from_first_and_rest(first, rest), *few);
+    }
+}
+
+#[test]
+fn from_first_and_rest_missing_first() {
+    assert_eq!(Few::from_first_and_rest(None, Some(5)), Few::One(5));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// retain_counting
+////////////////////////////////////////////////////////////////////////////////
+fn greater_than_one(v: &i32) -> bool { *v > 1 }
+
+#[test]
+fn retain_counting_two() {
+    let cases = [
+        (Few::Two(3, 2), 0, Few::Two(3, 2)),
+        (Few::Two(1, 2), 1, Few::One(2)),
+        (Few::Two(2, 1), 1, Few::One(2)),
+        (Few::Two(0, 1), 2, Few::Zero),
+    ];
+    for (few, removed, expected) in cases.iter() {
+        let mut retained = *few;
+        assert_eq!(retained.retain_counting(greater_than_one), *removed);
+        assert_eq!(retained, *expected);
+    }
+}
+
+#[test]
+fn retain_counting_one_and_zero() {
+    let mut few = Few::One(2);
+    assert_eq!(few.retain_counting(greater_than_one), 0);
+    assert_eq!(few, Few::One(2));
+    let mut few = Few::One(0);
+    assert_eq!(few.retain_counting(greater_than_one), 1);
+    assert_eq!(few, Few::Zero);
+    let mut few = Few::Zero;
+    assert_eq!(few.retain_counting(greater_than_one), 0);
+    assert_eq!(few, Few::Zero);
+}
+
+#[test]
+fn retain_counting_visits_in_order() {
+    let mut visited = Vec::new();
+    let mut few = Few::Two(1, 2);
+    let _ = few.retain_counting(|v| { visited.push(*v); true });
+    assert_eq!(visited, vec![1, 2]);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// map_accumulate
+////////////////////////////////////////////////////////////////////////////////
+fn sum_and_scale(acc: i32, v: i32) -> (i32, i32) { (acc + v, acc * v) }
+
+#[test]
+fn map_accumulate_each_variant() {
+    assert_eq!(
+        Few::Zero.map_accumulate(10, sum_and_scale),
+        (10, Few::Zero));
+    assert_eq!(
+        Few::One(1).map_accumulate(10, sum_and_scale),
+        (11, Few::One(10)));
+    assert_eq!(
+        Few::Two(1, 2).map_accumulate(10, sum_and_scale),
+        (13, Few::Two(10, 22)));
+}
+
+#[test]
+fn map_accumulate_changes_type() {
+    let (count, few) = Few::Two("a", "bc")
+        .map_accumulate(0, |acc, s| (acc + s.len(), s.to_uppercase()));
+    assert_eq!(count, 3);
+    assert_eq!(few, Few::Two(String::from("A"), String::from("BC")));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// try_extend_from_slice
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn try_extend_from_slice_fits() {
+    let mut few = Few::<i32>::Zero;
+    assert_eq!(few.try_extend_from_slice(&[]), Ok(()));
+    assert_eq!(few, Few::Zero);
+    assert_eq!(few.try_extend_from_slice(&[1]), Ok(()));
+    assert_eq!(few, Few::One(1));
+    assert_eq!(few.try_extend_from_slice(&[2]), Ok(()));
+    assert_eq!(few, Few::Two(1, 2));
+
+    let mut few = Few::Zero;
+    assert_eq!(few.try_extend_from_slice(&[1, 2]), Ok(()));
+    assert_eq!(few, Few::Two(1, 2));
+}
+
+#[test]
+fn try_extend_from_slice_excess() {
+    let mut few = Few::Zero;
+    assert_eq!(few.try_extend_from_slice(&[1, 2, 3, 4]), Err(2));
+    assert_eq!(few, Few::Two(1, 2));
+
+    let mut few = Few::One(0);
+    assert_eq!(few.try_extend_from_slice(&[1, 2, 3]), Err(2));
+    assert_eq!(few, Few::Two(0, 1));
+
+    let mut few = Few::Two(0, 0);
+    assert_eq!(few.try_extend_from_slice(&[1]), Err(1));
+    assert_eq!(few, Few::Two(0, 0));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// iter_padded
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn iter_padded_each_variant() {
+    assert_eq!(
+        Few::<i32>::Zero.iter_padded().collect::<Vec<_>>(),
+        vec![None, None]);
+    assert_eq!(
+        Few::One(1).iter_padded().collect::<Vec<_>>(),
+        vec![Some(1), None]);
+    assert_eq!(
+        Few::Two(1, 2).iter_padded().collect::<Vec<_>>(),
+        vec![Some(1), Some(2)]);
+}
+
+#[test]
+fn iter_padded_count() {
+    assert_eq!(Few::<i32>::Zero.iter_padded().count(), 2);
+    assert_eq!(Few::One(1).iter_padded().count(), 2);
+    assert_eq!(Few::Two(1, 2).iter_padded().count(), 2);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// dedup_by
+////////////////////////////////////////////////////////////////////////////////
+fn roughly_equal(a: &f64, b: &f64) -> bool { (a - b).abs() < 0.1 }
+
+#[test]
+fn dedup_by_two() {
+    let mut few = Few::Two(1.0, 1.05);
+    few.dedup_by(roughly_equal);
+    assert_eq!(few, Few::One(1.0));
+
+    let mut few = Few::Two(1.0, 2.0);
+    few.dedup_by(roughly_equal);
+    assert_eq!(few, Few::Two(1.0, 2.0));
+
+    let mut few = Few::Two("Ab", "ab");
+    few.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    assert_eq!(few, Few::One("Ab"));
+}
+
+#[test]
+fn dedup_by_one_and_zero() {
+    let mut calls = 0;
+    let mut few = Few::One(1);
+    few.dedup_by(|_, _| { calls += 1; true });
+    assert_eq!(few, Few::One(1));
+    let mut few = Few::<i32>::Zero;
+    few.dedup_by(|_, _| { calls += 1; true });
+    assert_eq!(few, Few::Zero);
+    assert_eq!(calls, 0);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// try_into_few
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn try_into_few_success() {
+    assert_eq!(Few::<i64>::Zero.try_into_few::<u8>(), Ok(Few::Zero));
+    assert_eq!(Few::One(1i64).try_into_few::<u8>(), Ok(Few::One(1)));
+    assert_eq!(Few::Two(1i64, 2).try_into_few::<u8>(), Ok(Few::Two(1, 2)));
+}
+
+#[test]
+fn try_into_few_failure() {
+    assert!(Few::One(300i64).try_into_few::<u8>().is_err());
+    assert!(Few::Two(-1i64, 2).try_into_few::<u8>().is_err());
+    assert!(Few::Two(1i64, -2).try_into_few::<u8>().is_err());
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// replace_if_zero
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn replace_if_zero_zero() {
+    let mut few = Few::Zero;
+    assert!(few.replace_if_zero(Few::Two(1, 2)));
+    assert_eq!(few, Few::Two(1, 2));
+
+    let mut few = Few::<i32>::Zero;
+    assert!(few.replace_if_zero(Few::Zero));
+    assert_eq!(few, Few::Zero);
+}
+
+#[test]
+fn replace_if_zero_nonzero() {
+    let mut few = Few::One(1);
+    assert!(!few.replace_if_zero(Few::Two(8, 9)));
+    assert_eq!(few, Few::One(1));
+
+    let mut few = Few::Two(1, 2);
+    assert!(!few.replace_if_zero(Few::One(9)));
+    assert_eq!(few, Few::Two(1, 2));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// into_iter_rev
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn into_iter_rev_each_variant() {
+    assert_eq!(Few::<i32>::Zero.into_iter_rev().next(), None);
+    assert_eq!(Few::One(1).into_iter_rev().collect::<Vec<_>>(), vec![1]);
+    assert_eq!(Few::Two(1, 2).into_iter_rev().collect::<Vec<_>>(), vec![2, 1]);
+}
+
+#[test]
+fn into_iter_rev_len_and_back() {
+    let mut iter = Few::Two(1, 2).into_iter_rev();
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next_back(), Some(1));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn into_iter_rev_rev() {
+    assert_eq!(
+        Few::Two(1, 2).into_iter_rev().rev().collect::<Vec<_>>(),
+        vec![1, 2]);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// swap_with
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn swap_with_different_variants() {
+    let mut a = Few::One(1);
+    let mut b = Few::Two(2, 3);
+    a.swap_with(&mut b);
+    assert_eq!((a, b), (Few::Two(2, 3), Few::One(1)));
+
+    let mut a = Few::Zero;
+    let mut b = Few::One(1);
+    a.swap_with(&mut b);
+    assert_eq!((a, b), (Few::One(1), Few::Zero));
+}
+
+#[test]
+fn swap_with_twice_restores() {
+    let mut a = Few::Two(1, 2);
+    let mut b = Few::Zero;
+    a.swap_with(&mut b);
+    b.swap_with(&mut a);
+    assert_eq!((a, b), (Few::Two(1, 2), Few::Zero));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// debug_invariants
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn debug_invariants_each_variant() {
+    Few::<u8>::Zero.debug_invariants();
+    Few::One(1u8).debug_invariants();
+    Few::Two(1u8, 2).debug_invariants();
+}
+
+#[test]
+fn debug_invariants_each_type() {
+    Few::Two(1u16, 2).debug_invariants();
+    Few::Two(1u64, 2).debug_invariants();
+    Few::Two((1u8, 2u32), (3, 4)).debug_invariants();
+    Few::Two(String::from("a"), String::from("b")).debug_invariants();
+    Few::Two((), ()).debug_invariants();
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// pair_sorted
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn pair_sorted_orders() {
+    assert_eq!(Few::pair_sorted(1, 2), Few::Two(1, 2));
+    assert_eq!(Few::pair_sorted(2, 1), Few::Two(1, 2));
+    assert_eq!(Few::pair_sorted("b", "a"), Few::Two("a", "b"));
+}
+
+#[test]
+fn pair_sorted_keeps_equal_values() {
+    assert_eq!(Few::pair_sorted(3, 3), Few::Two(3, 3));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// take_pair
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn take_pair_two() {
+    let mut few = Few::Two(1, 2);
+    assert_eq!(few.take_pair(), Some((1, 2)));
+    assert_eq!(few, Few::Zero);
+    assert_eq!(few.take_pair(), None);
+}
+
+#[test]
+fn take_pair_leaves_one_and_zero() {
+    let mut few = Few::One(String::from("a"));
+    assert_eq!(few.take_pair(), None);
+    assert_eq!(few, Few::One(String::from("a")));
+
+    let mut few = Few::<i32>::Zero;
+    assert_eq!(few.take_pair(), None);
+    assert_eq!(few, Few::Zero);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// map_if
+////////////////////////////////////////////////////////////////////////////////
+fn is_even(v: &i32) -> bool { v % 2 == 0 }
+
+#[test]
+fn map_if_two() {
+    assert_eq!(Few::Two(1, 2).map_if(is_even, |v| v * 10), Few::Two(1, 20));
+    assert_eq!(Few::Two(2, 1).map_if(is_even, |v| v * 10), Few::Two(20, 1));
+    assert_eq!(Few::Two(2, 4).map_if(is_even, |v| v * 10), Few::Two(20, 40));
+    assert_eq!(Few::Two(1, 3).map_if(is_even, |v| v * 10), Few::Two(1, 3));
+}
+
+#[test]
+fn map_if_one_and_zero() {
+    assert_eq!(Few::One(2).map_if(is_even, |v| v * 10), Few::One(20));
+    assert_eq!(Few::One(1).map_if(is_even, |v| v * 10), Few::One(1));
+    assert_eq!(Few::Zero.map_if(is_even, |v| v * 10), Few::Zero);
+}
+
+#[test]
+fn map_if_skips_unmatched() {
+    let mut calls = 0;
+    let _ = Few::Two(1, 2).map_if(is_even, |v| { calls += 1; v });
+    assert_eq!(calls, 1);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// partition_point
+////////////////////////////////////////////////////////////////////////////////
+fn less_than_three(v: &i32) -> bool { *v < 3 }
+
+#[test]
+fn partition_point_two() {
+    assert_eq!(Few::Two(1, 2).partition_point(less_than_three), 2);
+    assert_eq!(Few::Two(1, 5).partition_point(less_than_three), 1);
+    assert_eq!(Few::Two(4, 5).partition_point(less_than_three), 0);
+}
+
+#[test]
+fn partition_point_one_and_zero() {
+    assert_eq!(Few::One(1).partition_point(less_than_three), 1);
+    assert_eq!(Few::One(4).partition_point(less_than_three), 0);
+    assert_eq!(Few::Zero.partition_point(less_than_three), 0);
+}
+
+#[test]
+fn partition_point_matches_slice() {
+    for few in [Few::Two(1, 2), Few::Two(1, 5), Few::Two(4, 5)].iter() {
+        let values: Vec<i32> = few.iter_copied().collect();
+        assert_eq!(
+            few.partition_point(less_than_three),
+            values.partition_point(less_than_three));
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// iter_mut_rev
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn iter_mut_rev_order() {
+    let mut few = Few::Two(1, 2);
+    let mut order = Vec::new();
+    for (i, v) in few.iter_mut_rev().enumerate() {
+        order.push(*v);
+        *v += 10 * (i as i32 + 1);
+    }
+    assert_eq!(order, vec![2, 1]);
+    assert_eq!(few, Few::Two(21, 12));
+}
+
+#[test]
+fn iter_mut_rev_len_and_back() {
+    let mut few = Few::Two(1, 2);
+    let mut iter = few.iter_mut_rev();
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next_back().map(|v| *v), Some(1));
+    assert_eq!(iter.len(), 1);
+    assert_eq!(iter.next().map(|v| *v), Some(2));
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn iter_mut_rev_one_and_zero() {
+    let mut few = Few::One(1);
+    for v in few.iter_mut_rev() { *v = 5; }
+    assert_eq!(few, Few::One(5));
+    assert!(Few::<i32>::Zero.iter_mut_rev().next().is_none());
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// contains_count
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn contains_count_two() {
+    assert_eq!(Few::Two(1, 1).contains_count(&1), 2);
+    assert_eq!(Few::Two(1, 2).contains_count(&1), 1);
+    assert_eq!(Few::Two(1, 2).contains_count(&2), 1);
+    assert_eq!(Few::Two(1, 2).contains_count(&3), 0);
+}
+
+#[test]
+fn contains_count_one_and_zero() {
+    assert_eq!(Few::One(1).contains_count(&1), 1);
+    assert_eq!(Few::One(1).contains_count(&2), 0);
+    assert_eq!(Few::Zero.contains_count(&1), 0);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// lerp
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn lerp_two() {
+    let few = Few::Two(2.0, 6.0);
+    assert_eq!(few.lerp(0.0), Some(2.0));
+    assert_eq!(few.lerp(0.5), Some(4.0));
+    assert_eq!(few.lerp(1.0), Some(6.0));
+}
+
+#[test]
+fn lerp_reversed_and_extrapolated() {
+    let few = Few::Two(6.0, 2.0);
+    assert_eq!(few.lerp(0.0), Some(6.0));
+    assert_eq!(few.lerp(0.5), Some(4.0));
+    assert_eq!(few.lerp(1.0), Some(2.0));
+    assert_eq!(Few::Two(2.0, 6.0).lerp(1.5), Some(8.0));
+    assert_eq!(Few::Two(2.0, 6.0).lerp(-0.5), Some(0.0));
+}
+
+#[test]
+fn lerp_one_and_zero() {
+    assert_eq!(Few::One(3.0).lerp(0.0), Some(3.0));
+    assert_eq!(Few::One(3.0).lerp(0.5), Some(3.0));
+    assert_eq!(Few::One(3.0).lerp(1.0), Some(3.0));
+    assert_eq!(Few::<f64>::Zero.lerp(0.5), None);
+}
+
+#[test]
+fn lerp_wrapper_type() {
+    #[derive(Debug, Clone, PartialEq)]
+    struct Meters(f64);
+    impl From<f64> for Meters {
+        fn from(v: f64) -> Self { Meters(v) }
+    }
+    impl From<Meters> for f64 {
+        fn from(v: Meters) -> Self { v.0 }
+    }
+    let few = Few::Two(Meters(0.0), Meters(10.0));
+    assert_eq!(few.lerp(0.5), Some(Meters(5.0)));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// into_ok_iter
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn into_ok_iter_mixed() {
+    let few: Few<Result<i32, char>> = Few::Two(Ok(1), Err('a'));
+    assert_eq!(few.into_ok_iter().collect::<Vec<_>>(), vec![1]);
+    let few: Few<Result<i32, char>> = Few::Two(Err('a'), Ok(2));
+    assert_eq!(few.into_ok_iter().collect::<Vec<_>>(), vec![2]);
+}
+
+#[test]
+fn into_ok_iter_uniform() {
+    let few: Few<Result<i32, char>> = Few::Two(Ok(1), Ok(2));
+    assert_eq!(few.into_ok_iter().collect::<Vec<_>>(), vec![1, 2]);
+    let few: Few<Result<i32, char>> = Few::Two(Err('a'), Err('b'));
+    assert_eq!(few.into_ok_iter().count(), 0);
+    let few: Few<Result<i32, char>> = Few::Zero;
+    assert_eq!(few.into_ok_iter().count(), 0);
+}
+
+#[test]
+fn into_err_iter_mixed() {
+    let few: Few<Result<i32, char>> = Few::Two(Ok(1), Err('a'));
+    assert_eq!(few.into_err_iter().collect::<Vec<_>>(), vec!['a']);
+    let few: Few<Result<i32, char>> = Few::Two(Err('a'), Err('b'));
+    assert_eq!(few.into_err_iter().collect::<Vec<_>>(), vec!['a', 'b']);
+    let few: Few<Result<i32, char>> = Few::One(Ok(1));
+    assert_eq!(few.into_err_iter().count(), 0);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// flat_map_to_vec
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn flat_map_to_vec_each_variant() {
+    assert_eq!(Few::Two(1, 3).flat_map_to_vec(|v| 0..v), vec![0, 0, 1, 2]);
+    assert_eq!(Few::One(2).flat_map_to_vec(|v| 0..v), vec![0, 1]);
+    assert!(Few::<i32>::Zero.flat_map_to_vec(|v| 0..v).is_empty());
+}
+
+#[test]
+fn flat_map_to_vec_empty_results() {
+    assert!(Few::Two(0, 0).flat_map_to_vec(|v| 0..v).is_empty());
+    assert_eq!(
+        Few::Two("ab", "").flat_map_to_vec(str::chars),
+        vec!['a', 'b']);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// variant_name
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn variant_name_each_variant() {
+    assert_eq!(Few::<i32>::Zero.variant_name(), "Zero");
+    assert_eq!(Few::One(1).variant_name(), "One");
+    assert_eq!(Few::Two(1, 2).variant_name(), "Two");
+}
+
+#[test]
+fn variant_name_const() {
+    const NAME: &str = Few::<i32>::Zero.variant_name();
+    assert_eq!(NAME, "Zero");
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// reduce_few
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn reduce_few_combines_in_order() {
+    let mut calls = Vec::new();
+    let reduced = reduce_few(
+        vec![Few::One(1), Few::Two(2, 3), Few::One(4)],
+        |a, b| {
+            calls.push((a, b));
+            b
+        });
+    assert_eq!(reduced, Few::One(4));
+    assert_eq!(calls, vec![
+        (Few::One(1), Few::Two(2, 3)),
+        (Few::Two(2, 3), Few::One(4)),
+    ]);
+}
+
+#[test]
+fn reduce_few_envelope() {
+    let ranges = vec![Few::Two(3, 5), Few::One(1), Few::Zero, Few::Two(9, 7)];
+    let reduced = reduce_few(ranges, |a, b| bounding_few(a.chain(b)));
+    assert_eq!(reduced, Few::Two(1, 9));
+}
+
+#[test]
+fn reduce_few_single_and_empty() {
+    assert_eq!(reduce_few(vec![Few::One(1)], |_, _| Few::Zero), Few::One(1));
+    assert_eq!(reduce_few(Vec::<Few<i32>>::new(), |a, _| a), Few::Zero);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// try_pair
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn try_pair_two() {
+    let few = Few::Two(1, 2);
+    assert_eq!(few.try_pair(), Ok((&1, &2)));
+}
+
+#[test]
+fn try_pair_one_and_zero() {
+    assert_eq!(
+        Few::One(1).try_pair(),
+        Err(FewError { expected: "Two", found: "One" }));
+    assert_eq!(
+        Few::<i32>::Zero.try_pair(),
+        Err(FewError { expected: "Two", found: "Zero" }));
+}
+
+#[test]
+fn few_error_display() {
+    let err = Few::One(1).try_pair().unwrap_err();
+    assert_eq!(err.to_string(), "expected `Few::Two`, found `Few::One`");
+    let err: Box<dyn std::error::Error> = Box::new(err);
+    assert!(err.source().is_none());
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// map_pair_or
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn map_pair_or_each_variant() {
+    assert_eq!(Few::<i32>::Zero.map_pair_or(0, |v| v * 10), (0, 0));
+    assert_eq!(Few::One(1).map_pair_or(0, |v| v * 10), (10, 0));
+    assert_eq!(Few::Two(1, 2).map_pair_or(0, |v| v * 10), (10, 20));
+}
+
+#[test]
+fn map_pair_or_call_order() {
+    let mut calls = Vec::new();
+    let pair = Few::Two('a', 'b').map_pair_or(String::new(), |v| {
+        calls.push(v);
+        v.to_string()
+    });
+    assert_eq!(pair, (String::from("a"), String::from("b")));
+    assert_eq!(calls, vec!['a', 'b']);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// keep_extreme_by_key
+////////////////////////////////////////////////////////////////////////////////
+fn magnitude(v: &i32) -> i32 { v.abs() }
+
+#[test]
+fn keep_extreme_by_key_two() {
+    assert_eq!(Few::Two(-5, 3).keep_extreme_by_key(magnitude), Few::One(-5));
+    assert_eq!(Few::Two(3, -5).keep_extreme_by_key(magnitude), Few::One(-5));
+}
+
+#[test]
+fn keep_extreme_by_key_tie_keeps_second() {
+    assert_eq!(Few::Two(-3, 3).keep_extreme_by_key(magnitude), Few::One(3));
+}
+
+#[test]
+fn keep_extreme_by_key_one_and_zero() {
+    assert_eq!(Few::One(-5).keep_extreme_by_key(magnitude), Few::One(-5));
+    assert_eq!(Few::<i32>::Zero.keep_extreme_by_key(magnitude), Few::Zero);
+}
+
+#[test]
+fn keep_min_by_key_two() {
+    assert_eq!(Few::Two(-5, 3).keep_min_by_key(magnitude), Few::One(3));
+    assert_eq!(Few::Two(3, -5).keep_min_by_key(magnitude), Few::One(3));
+}
+
+#[test]
+fn keep_min_by_key_tie_keeps_first() {
+    assert_eq!(Few::Two(-3, 3).keep_min_by_key(magnitude), Few::One(-3));
+}
+
+#[test]
+fn keep_min_by_key_one_and_zero() {
+    assert_eq!(Few::One(-5).keep_min_by_key(magnitude), Few::One(-5));
+    assert_eq!(Few::<i32>::Zero.keep_min_by_key(magnitude), Few::Zero);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// zip_indexed_map
+////////////////////////////////////////////////////////////////////////////////
+fn indexed_sum(i: usize, a: i32, b: i32) -> (usize, i32) { (i, a + b) }
+
+#[test]
+fn zip_indexed_map_same_length() {
+    assert_eq!(
+        Few::Two(1, 2).zip_indexed_map(Few::Two(10, 20), indexed_sum),
+        Few::Two((0, 11), (1, 22)));
+    assert_eq!(
+        Few::One(1).zip_indexed_map(Few::One(10), indexed_sum),
+        Few::One((0, 11)));
+    assert_eq!(
+        Few::Zero.zip_indexed_map(Few::Zero, indexed_sum),
+        Few::Zero);
+}
+
+#[test]
+fn zip_indexed_map_shorter_length() {
+    assert_eq!(
+        Few::Two(1, 2).zip_indexed_map(Few::One(10), indexed_sum),
+        Few::One((0, 11)));
+    assert_eq!(
+        Few::One(1).zip_indexed_map(Few::Two(10, 20), indexed_sum),
+        Few::One((0, 11)));
+    assert_eq!(
+        Few::Two(1, 2).zip_indexed_map(Few::Zero, indexed_sum),
+        Few::Zero);
+    assert_eq!(
+        Few::Zero.zip_indexed_map(Few::One(10), indexed_sum),
+        Few::Zero);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// into_sorted_vec
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn into_sorted_vec_each_variant() {
+    assert_eq!(Few::Two(2, 1).into_sorted_vec(), vec![1, 2]);
+    assert_eq!(Few::Two(1, 2).into_sorted_vec(), vec![1, 2]);
+    assert_eq!(Few::Two(2, 2).into_sorted_vec(), vec![2, 2]);
+    assert_eq!(Few::One(1).into_sorted_vec(), vec![1]);
+    assert_eq!(Few::<i32>::Zero.into_sorted_vec(), vec![]);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// to_vec_interspersed_with
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn to_vec_interspersed_with_two() {
+    let mut calls = 0;
+    let values = Few::Two(1, 2).to_vec_interspersed_with(|| {
+        calls += 1;
+        0
+    });
+    assert_eq!(values, vec![1, 0, 2]);
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn to_vec_interspersed_with_one_and_zero() {
+    let mut calls = 0;
+    let values = Few::One(1).to_vec_interspersed_with(|| { calls += 1; 0 });
+    assert_eq!(values, vec![1]);
+    let values = Few::Zero.to_vec_interspersed_with(|| { calls += 1; 0 });
+    assert_eq!(values, vec![]);
+    assert_eq!(calls, 0);
+}
@@ -0,0 +1,124 @@
+////////////////////////////////////////////////////////////////////////////////
+// Few -- A generalization of `std::Option` allowing for up to two optional
+// values.
+////////////////////////////////////////////////////////////////////////////////
+// Copyright 2020 Skylor R. Schermer
+// This code is dual licenced using the MIT or Apache 2 license.
+// See licence-mit.md and licence-apache.md for details.
+////////////////////////////////////////////////////////////////////////////////
+//! State transition and property tests for `Few::fill_one_more`.
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use few::Few;
+
+// External library imports.
+use proptest::prelude::*;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// State transitions
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn zero_to_one() {
+    let mut few = Few::Zero;
+    assert_eq!(few.fill_one_more(1), None);
+    assert_eq!(few, Few::One(1));
+}
+
+#[test]
+fn one_to_two() {
+    let mut few = Few::One(1);
+    assert_eq!(few.fill_one_more(2), None);
+    assert_eq!(few, Few::Two(1, 2));
+}
+
+#[test]
+fn two_rejects() {
+    let mut few = Few::Two(1, 2);
+    assert_eq!(few.fill_one_more(3), Some(3));
+    assert_eq!(few, Few::Two(1, 2));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Push/pop sequences
+////////////////////////////////////////////////////////////////////////////////
+/// An operation applied to both a `Few` and its model.
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Push,
+    PopFront,
+    PopBack,
+}
+
+fn op() -> impl Strategy<Value=Op> {
+    prop_oneof![
+        2 => Just(Op::Push),
+        1 => Just(Op::PopFront),
+        1 => Just(Op::PopBack),
+    ]
+}
+
+proptest! {
+    /// Checks that `fill_one_more` behaves like a vector with a capacity of
+    /// two.
+    #[test]
+    fn matches_bounded_vec(ops in prop::collection::vec(op(), 0..64)) {
+        let mut few = Few::Zero;
+        let mut model: Vec<usize> = Vec::new();
+
+        for (value, op) in ops.into_iter().enumerate() {
+            match op {
+                Op::Push if model.len() < 2 => {
+                    prop_assert_eq!(few.fill_one_more(value), None);
+                    model.push(value);
+                },
+                Op::Push => {
+                    prop_assert_eq!(few.fill_one_more(value), Some(value));
+                },
+                Op::PopFront => {
+                    let expected = if model.is_empty() {
+                        None
+                    } else {
+                        Some(model.remove(0))
+                    };
+                    prop_assert_eq!(few.next(), expected);
+                },
+                Op::PopBack => {
+                    prop_assert_eq!(few.next_back(), model.pop());
+                },
+            }
+            prop_assert_eq!(few.len(), model.len());
+            prop_assert_eq!(few.iter_copied().collect::<Vec<_>>(), &model[..]);
+        }
+    }
+
+    /// Checks that every pushed value is either stored, returned, or popped
+    /// exactly once.
+    #[test]
+    fn conserves_values(ops in prop::collection::vec(op(), 0..64)) {
+        let mut few = Few::Zero;
+        let mut pushed = Vec::new();
+        let mut rejected = Vec::new();
+        let mut popped = Vec::new();
+
+        for (value, op) in ops.into_iter().enumerate() {
+            match op {
+                Op::Push => {
+                    pushed.push(value);
+                    rejected.extend(few.fill_one_more(value));
+                },
+                Op::PopFront => popped.extend(few.next()),
+                Op::PopBack  => popped.extend(few.next_back()),
+            }
+        }
+
+        let mut seen: Vec<usize> = few
+            .chain(rejected)
+            .chain(popped)
+            .collect();
+        seen.sort_unstable();
+        prop_assert_eq!(seen, pushed);
+    }
+}
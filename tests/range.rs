@@ -0,0 +1,653 @@
+////////////////////////////////////////////////////////////////////////////////
+// Few -- A generalization of `std::Option` allowing for up to two optional
+// values.
+////////////////////////////////////////////////////////////////////////////////
+// Copyright 2020 Skylor R. Schermer
+// This code is dual licenced using the MIT or Apache 2 license.
+// See licence-mit.md and licence-apache.md for details.
+////////////////////////////////////////////////////////////////////////////////
+//! Tests for `Few` methods and functions interpreting `Few`s as ranges.
+////////////////////////////////////////////////////////////////////////////////
+
+// Internal library imports.
+use few::*;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// check_range_invariant
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn check_range_invariant_well_formed() {
+    assert!(Few::<i32>::Zero.check_range_invariant());
+    assert!(Few::One(3).check_range_invariant());
+    assert!(Few::Two(1, 3).check_range_invariant());
+    assert!(Few::Two(3, 3).check_range_invariant());
+}
+
+#[test]
+fn check_range_invariant_malformed() {
+    assert!(!Few::Two(4, 3).check_range_invariant());
+}
+
+#[test]
+fn debug_assert_range_well_formed() {
+    Few::<i32>::Zero.debug_assert_range();
+    Few::One(3).debug_assert_range();
+    Few::Two(1, 3).debug_assert_range();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "malformed `Few` range: Two(4, 3)")]
+fn debug_assert_range_malformed() {
+    Few::Two(4, 3).debug_assert_range();
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// as_range
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn as_range_in_order() {
+    assert_eq!(Few::Two(1, 2).as_range(), Some((&1, &2)));
+}
+
+#[test]
+fn as_range_reversed() {
+    assert_eq!(Few::Two(2, 1).as_range(), Some((&1, &2)));
+}
+
+#[test]
+fn as_range_one_and_zero() {
+    assert_eq!(Few::One(1).as_range(), None);
+    assert_eq!(Few::<i32>::Zero.as_range(), None);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// merge_into
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn merge_into_overlapping() {
+    let mut out = Vec::new();
+    Few::Two(1, 5).merge_into(&mut out, Few::Two(4, 8));
+    assert_eq!(out, vec![Few::Two(1, 8)]);
+
+    let mut out = Vec::new();
+    Few::One(5).merge_into(&mut out, Few::Two(5, 7));
+    assert_eq!(out, vec![Few::Two(5, 7)]);
+
+    let mut out = Vec::new();
+    Few::One(5).merge_into(&mut out, Few::One(5));
+    assert_eq!(out, vec![Few::One(5)]);
+}
+
+#[test]
+fn merge_into_disjoint() {
+    let mut out = Vec::new();
+    Few::Two(6, 9).merge_into(&mut out, Few::Two(3, 1));
+    assert_eq!(out, vec![Few::Two(1, 3), Few::Two(6, 9)]);
+}
+
+#[test]
+fn merge_into_zero() {
+    let mut out = Vec::new();
+    Few::Zero.merge_into(&mut out, Few::Zero);
+    assert_eq!(out, vec![]);
+
+    Few::Zero.merge_into(&mut out, Few::Two(2, 1));
+    assert_eq!(out, vec![Few::Two(1, 2)]);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// envelope
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn envelope_overlapping() {
+    assert_eq!(Few::Two(1, 5).envelope(Few::Two(3, 8)), Few::Two(1, 8));
+    assert_eq!(Few::Two(5, 1).envelope(Few::One(3)), Few::Two(1, 5));
+    assert_eq!(Few::One(3).envelope(Few::One(3)), Few::One(3));
+}
+
+#[test]
+fn envelope_disjoint() {
+    assert_eq!(Few::Two(1, 2).envelope(Few::Two(9, 7)), Few::Two(1, 9));
+    assert_eq!(Few::One(1).envelope(Few::One(3)), Few::Two(1, 3));
+}
+
+#[test]
+fn envelope_zero() {
+    assert_eq!(Few::Zero.envelope(Few::One(3)), Few::One(3));
+    assert_eq!(Few::<i32>::Zero.envelope(Few::Zero), Few::Zero);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// relation_to
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn relation_to_each_variant() {
+    assert_eq!(Few::Two(1, 2).relation_to(&Few::Two(3, 4)),
+        RangeRelation::Before);
+    assert_eq!(Few::Two(1, 3).relation_to(&Few::Two(3, 4)),
+        RangeRelation::Overlaps);
+    assert_eq!(Few::Two(1, 9).relation_to(&Few::Two(3, 4)),
+        RangeRelation::Contains);
+    assert_eq!(Few::Two(3, 4).relation_to(&Few::Two(1, 9)),
+        RangeRelation::ContainedBy);
+    assert_eq!(Few::Two(5, 6).relation_to(&Few::Two(3, 4)),
+        RangeRelation::After);
+    assert_eq!(Few::Two(3, 4).relation_to(&Few::Two(3, 4)),
+        RangeRelation::Equal);
+}
+
+#[test]
+fn relation_to_reversed() {
+    assert_eq!(Few::Two(3, 4).relation_to(&Few::Two(4, 1)),
+        RangeRelation::ContainedBy);
+    assert_eq!(Few::Two(4, 3).relation_to(&Few::Two(3, 4)),
+        RangeRelation::Equal);
+}
+
+#[test]
+fn relation_to_one() {
+    assert_eq!(Few::One(3).relation_to(&Few::Two(3, 4)),
+        RangeRelation::ContainedBy);
+    assert_eq!(Few::One(2).relation_to(&Few::One(3)),
+        RangeRelation::Before);
+    assert_eq!(Few::One(3).relation_to(&Few::One(3)),
+        RangeRelation::Equal);
+}
+
+#[test]
+fn relation_to_zero() {
+    assert_eq!(Few::Zero.relation_to(&Few::One(3)),
+        RangeRelation::ContainedBy);
+    assert_eq!(Few::One(3).relation_to(&Few::Zero),
+        RangeRelation::Contains);
+    assert_eq!(Few::<i32>::Zero.relation_to(&Few::Zero),
+        RangeRelation::Equal);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// coalesce_ranges
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn coalesce_ranges_overlapping() {
+    let mut ranges = vec![Few::Two(1, 10), Few::Two(2, 3), Few::Two(8, 12)];
+    coalesce_ranges(&mut ranges);
+    assert_eq!(ranges, vec![Few::Two(1, 12)]);
+}
+
+#[test]
+fn coalesce_ranges_adjacent() {
+    let mut ranges = vec![Few::Two(3, 5), Few::Two(1, 3), Few::One(5)];
+    coalesce_ranges(&mut ranges);
+    assert_eq!(ranges, vec![Few::Two(1, 5)]);
+}
+
+#[test]
+fn coalesce_ranges_disjoint() {
+    let mut ranges = vec![Few::Two(5, 8), Few::One(10), Few::Two(2, 1)];
+    coalesce_ranges(&mut ranges);
+    assert_eq!(ranges, vec![Few::Two(1, 2), Few::Two(5, 8), Few::One(10)]);
+}
+
+#[test]
+fn coalesce_ranges_mixed() {
+    let mut ranges = vec![
+        Few::Two(5, 8), Few::Two(1, 3), Few::Zero, Few::Two(3, 4),
+        Few::One(10), Few::Two(7, 6), Few::One(2), Few::One(12),
+        Few::Two(12, 12),
+    ];
+    coalesce_ranges(&mut ranges);
+    assert_eq!(ranges, vec![
+        Few::Two(1, 4), Few::Two(5, 8), Few::One(10), Few::One(12),
+    ]);
+}
+
+#[test]
+fn coalesce_ranges_empty() {
+    let mut ranges: Vec<Few<i32>> = vec![Few::Zero];
+    coalesce_ranges(&mut ranges);
+    assert_eq!(ranges, vec![]);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// bucket_of
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn bucket_of_two() {
+    let range = Few::Two(3, 6);
+    assert_eq!(range.bucket_of(&1), Some(0));
+    assert_eq!(range.bucket_of(&3), Some(1));
+    assert_eq!(range.bucket_of(&4), Some(1));
+    assert_eq!(range.bucket_of(&6), Some(1));
+    assert_eq!(range.bucket_of(&7), Some(2));
+}
+
+#[test]
+fn bucket_of_reversed_two() {
+    let range = Few::Two(6, 3);
+    assert_eq!(range.bucket_of(&2), Some(0));
+    assert_eq!(range.bucket_of(&5), Some(1));
+    assert_eq!(range.bucket_of(&7), Some(2));
+}
+
+#[test]
+fn bucket_of_one_and_zero() {
+    assert_eq!(Few::One(3).bucket_of(&2), Some(0));
+    assert_eq!(Few::One(3).bucket_of(&3), Some(1));
+    assert_eq!(Few::One(3).bucket_of(&4), Some(2));
+    assert_eq!(Few::Zero.bucket_of(&2), None);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// coverage_points
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn coverage_points_sorted() {
+    let ranges = [Few::Two(5, 8), Few::Two(1, 3), Few::Two(7, 2)];
+    assert_eq!(coverage_points(&ranges), vec![
+        (1, 1), (2, 1), (3, -1), (5, 1), (7, -1), (8, -1),
+    ]);
+}
+
+#[test]
+fn coverage_points_shared_endpoint() {
+    let ranges = [Few::Two(3, 5), Few::Two(1, 3)];
+    assert_eq!(coverage_points(&ranges), vec![
+        (1, 1), (3, 1), (3, -1), (5, -1),
+    ]);
+}
+
+#[test]
+fn coverage_points_skips_zero_and_one() {
+    let ranges = [Few::Zero, Few::One(4), Few::Two(1, 2)];
+    assert_eq!(coverage_points(&ranges), vec![(1, 1), (2, -1)]);
+    assert!(coverage_points::<i32>(&[]).is_empty());
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// RangeBounds
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn range_bounds_one() {
+    use std::ops::{Bound, RangeBounds};
+    let range = Few::One(3);
+    assert_eq!(range.start_bound(), Bound::Included(&3));
+    assert_eq!(range.end_bound(), Bound::Included(&3));
+    assert!(range.contains(&3));
+    assert!(!range.contains(&4));
+}
+
+#[test]
+fn range_bounds_two() {
+    use std::ops::{Bound, RangeBounds};
+    let range = Few::Two(2, 5);
+    assert_eq!(range.start_bound(), Bound::Included(&2));
+    assert_eq!(range.end_bound(), Bound::Included(&5));
+    assert!(range.contains(&2));
+    assert!(range.contains(&5));
+    assert!(!range.contains(&6));
+}
+
+#[test]
+fn range_bounds_reversed_two() {
+    use std::ops::{Bound, RangeBounds};
+    let range = Few::Two(5, 2);
+    assert_eq!(range.start_bound(), Bound::Included(&5));
+    assert_eq!(range.end_bound(), Bound::Included(&2));
+    assert!(!range.contains(&3));
+}
+
+#[test]
+#[should_panic(expected = "`Zero` cannot be used as a range bound")]
+fn range_bounds_zero_start() {
+    use std::ops::RangeBounds;
+    let _ = Few::<i32>::Zero.start_bound();
+}
+
+#[test]
+#[should_panic(expected = "`Zero` cannot be used as a range bound")]
+fn range_bounds_zero_end() {
+    use std::ops::RangeBounds;
+    let _ = Few::<i32>::Zero.end_bound();
+}
+
+#[test]
+fn range_bounds_btree_map_range() {
+    let map: std::collections::BTreeMap<i32, char> = (0..10)
+        .zip("abcdefghij".chars())
+        .collect();
+
+    let values: String = map
+        .range(Few::Two(3, 6))
+        .map(|(_, c)| *c)
+        .collect();
+    assert_eq!(values, "defg");
+
+    let values: String = map.range(Few::One(8)).map(|(_, c)| *c).collect();
+    assert_eq!(values, "i");
+}
+
+#[test]
+fn range_bounds_btree_map_range_borrowed() {
+    let map: std::collections::BTreeMap<String, usize> = ["a", "b", "c", "d"]
+        .iter()
+        .map(|s| s.to_string())
+        .zip(0..)
+        .collect();
+
+    let few = Few::Two(String::from("b"), String::from("c"));
+    let values: Vec<usize> = map.range(&few).map(|(_, v)| *v).collect();
+    assert_eq!(values, vec![1, 2]);
+    assert_eq!(few, Few::Two(String::from("b"), String::from("c")));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// count_within
+////////////////////////////////////////////////////////////////////////////////
+const COUNT_VALUES: [i32; 7] = [0, 1, 3, 4, 5, 6, 9];
+
+#[test]
+fn count_within_two() {
+    assert_eq!(Few::Two(3, 6).count_within(&COUNT_VALUES), 4);
+    assert_eq!(Few::Two(6, 3).count_within(&COUNT_VALUES), 4);
+    assert_eq!(Few::Two(7, 8).count_within(&COUNT_VALUES), 0);
+}
+
+#[test]
+fn count_within_one_and_zero() {
+    assert_eq!(Few::One(4).count_within(&COUNT_VALUES), 1);
+    assert_eq!(Few::One(2).count_within(&COUNT_VALUES), 0);
+    assert_eq!(Few::Zero.count_within(&COUNT_VALUES), 0);
+    assert_eq!(Few::Two(0, 9).count_within(&[]), 0);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// merge_runs_by
+////////////////////////////////////////////////////////////////////////////////
+fn merge_touching(a: &Few<i32>, b: &Few<i32>) -> Option<Few<i32>> {
+    match (a, b) {
+        (Few::Two(a0, a1), Few::Two(b0, b1)) if *a1 + 1 >= *b0 => {
+            Some(Few::Two(*a0, *a1.max(b1)))
+        },
+        _ => None,
+    }
+}
+
+#[test]
+fn merge_runs_by_adjacent() {
+    let mut ranges = vec![
+        Few::Two(1, 2), Few::Two(3, 4), Few::Two(7, 8), Few::One(9),
+        Few::Two(10, 12), Few::Two(13, 14),
+    ];
+    merge_runs_by(&mut ranges, merge_touching);
+    assert_eq!(ranges, vec![
+        Few::Two(1, 4), Few::Two(7, 8), Few::One(9), Few::Two(10, 14),
+    ]);
+}
+
+#[test]
+fn merge_runs_by_cascades_backwards() {
+    let merge_equal = |a: &Few<i32>, b: &Few<i32>| match (a, b) {
+        (Few::One(a), Few::One(b)) if a == b => Some(Few::One(a + b)),
+        _                                    => None,
+    };
+    let mut values = vec![Few::One(4), Few::One(2), Few::One(1), Few::One(1)];
+    merge_runs_by(&mut values, merge_equal);
+    assert_eq!(values, vec![Few::One(8)]);
+}
+
+#[test]
+fn merge_runs_by_empty() {
+    let mut ranges: Vec<Few<i32>> = Vec::new();
+    merge_runs_by(&mut ranges, merge_touching);
+    assert!(ranges.is_empty());
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// subdivide
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn subdivide_two() {
+    let range = Few::Two(0, 10);
+    assert_eq!(range.subdivide(0), Some(vec![0]));
+    assert_eq!(range.subdivide(1), Some(vec![0, 10]));
+    assert_eq!(range.subdivide(2), Some(vec![0, 5, 10]));
+    assert_eq!(range.subdivide(3), Some(vec![0, 3, 6, 10]));
+}
+
+#[test]
+fn subdivide_one_and_zero() {
+    assert_eq!(Few::One(3).subdivide(4), Some(vec![3]));
+    assert_eq!(Few::<i32>::Zero.subdivide(4), Some(vec![]));
+}
+
+#[test]
+fn subdivide_reversed() {
+    assert_eq!(Few::Two(5u32, 1).subdivide(2), Some(vec![5, 3, 1]));
+    assert_eq!(Few::Two(10, 0).subdivide(3), Some(vec![10, 6, 3, 0]));
+    assert_eq!(Few::Two(5u32, 1).subdivide(0), Some(vec![5]));
+}
+
+#[test]
+fn subdivide_no_overflow() {
+    assert_eq!(Few::Two(0u8, 200).subdivide(2), Some(vec![0, 100, 200]));
+    assert_eq!(
+        Few::Two(0u8, u8::MAX).subdivide(255),
+        Some((0..=u8::MAX).collect()));
+    let points = Few::Two(0u8, 10).subdivide(255).unwrap();
+    assert_eq!(points.len(), 256);
+    assert_eq!(points.last(), Some(&10));
+}
+
+#[test]
+fn subdivide_too_many_parts() {
+    assert_eq!(Few::Two(0u8, 10).subdivide(256), None);
+    assert_eq!(Few::Two(0u8, 10).subdivide(300), None);
+    assert_eq!(Few::One(3u8).subdivide(300), Some(vec![3]));
+}
+
+#[test]
+fn subdivide_matches_formula() {
+    for lo in (0..=u8::MAX).step_by(15) {
+        for hi in (lo..=u8::MAX).step_by(7) {
+            for parts in 1..=20u8 {
+                let expected: Vec<u8> = (0..=parts)
+                    .map(|i| {
+                        let diff = u32::from(hi - lo);
+                        lo + (diff * u32::from(i) / u32::from(parts)) as u8
+                    })
+                    .collect();
+                let points = Few::Two(lo, hi).subdivide(parts.into());
+                assert_eq!(points, Some(expected), "{}..={} / {}",
+                    lo, hi, parts);
+            }
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// min_max
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn min_max_two() {
+    assert_eq!(Few::Two(1, 2).min_max(), Some((&1, &2)));
+    assert_eq!(Few::Two(2, 1).min_max(), Some((&1, &2)));
+    assert_eq!(Few::Two(2, 2).min_max(), Some((&2, &2)));
+}
+
+#[test]
+fn min_max_one_and_zero() {
+    let few = Few::One(3);
+    let (min, max) = few.min_max().unwrap();
+    assert!(std::ptr::eq(min, max));
+    assert_eq!(*min, 3);
+    assert_eq!(Few::<i32>::Zero.min_max(), None);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// range_refs
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn range_refs_two() {
+    let range = Few::Two(2, 5);
+    let refs = range.range_refs().unwrap();
+    assert!(std::ptr::eq(*refs.start(), range.peek().unwrap()));
+    assert!(std::ptr::eq(*refs.end(), range.peek_back().unwrap()));
+}
+
+#[test]
+fn range_refs_reversed_two() {
+    let range = Few::Two(5, 2);
+    let refs = range.range_refs().unwrap();
+    assert_eq!((**refs.start(), **refs.end()), (2, 5));
+    assert!(std::ptr::eq(*refs.start(), range.peek_back().unwrap()));
+    assert!(std::ptr::eq(*refs.end(), range.peek().unwrap()));
+}
+
+#[test]
+fn range_refs_one_and_zero() {
+    let range = Few::One(3);
+    let refs = range.range_refs().unwrap();
+    assert!(std::ptr::eq(*refs.start(), *refs.end()));
+    assert!(std::ptr::eq(*refs.start(), range.peek().unwrap()));
+    assert_eq!(Few::<i32>::Zero.range_refs(), None);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// max_overlap_depth
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn max_overlap_depth_nested() {
+    let ranges = [Few::Two(1, 10), Few::Two(2, 5), Few::Two(3, 4)];
+    assert_eq!(max_overlap_depth(&ranges), 3);
+}
+
+#[test]
+fn max_overlap_depth_shared_endpoint() {
+    let ranges = [Few::Two(1, 3), Few::Two(3, 5), Few::Two(5, 1)];
+    assert_eq!(max_overlap_depth(&ranges), 3);
+    let ranges = [Few::Two(1, 2), Few::Two(3, 4)];
+    assert_eq!(max_overlap_depth(&ranges), 1);
+}
+
+#[test]
+fn max_overlap_depth_ignores_zero_and_one() {
+    let ranges = [Few::One(2), Few::Zero, Few::Two(1, 3)];
+    assert_eq!(max_overlap_depth(&ranges), 1);
+    assert_eq!(max_overlap_depth(&[Few::One(2), Few::Zero]), 0);
+    assert_eq!(max_overlap_depth::<i32>(&[]), 0);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// expand_inclusive
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn expand_inclusive_two() {
+    assert_eq!(Few::Two(2, 5).expand_inclusive(), vec![2, 3, 4, 5]);
+    assert_eq!(Few::Two(5, 2).expand_inclusive(), vec![2, 3, 4, 5]);
+    assert_eq!(Few::Two(3, 3).expand_inclusive(), vec![3]);
+    assert_eq!(Few::Two('a', 'c').expand_inclusive(), vec!['a', 'b', 'c']);
+}
+
+#[test]
+fn expand_inclusive_one_and_zero() {
+    assert_eq!(Few::One(4).expand_inclusive(), vec![4]);
+    assert_eq!(Few::<i32>::Zero.expand_inclusive(), vec![]);
+}
+
+#[test]
+fn expand_inclusive_type_limit() {
+    let values = Few::Two(u8::MAX, u8::MAX - 2).expand_inclusive();
+    assert_eq!(values, vec![253, 254, 255]);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// filter_overlapping
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn filter_overlapping_two_query() {
+    let ranges = [
+        Few::Two(1, 2), Few::Two(2, 4), Few::Two(5, 6), Few::Two(9, 7),
+        Few::One(3), Few::One(8), Few::Zero, Few::Two(0, 10),
+    ];
+    assert_eq!(filter_overlapping(&ranges, &Few::Two(3, 7)), vec![
+        Few::Two(2, 4), Few::Two(5, 6), Few::Two(9, 7), Few::One(3),
+        Few::Two(0, 10),
+    ]);
+}
+
+#[test]
+fn filter_overlapping_one_query() {
+    let ranges = [Few::Two(1, 3), Few::Two(4, 6), Few::One(3), Few::One(4)];
+    assert_eq!(
+        filter_overlapping(&ranges, &Few::One(3)),
+        vec![Few::Two(1, 3), Few::One(3)]);
+}
+
+#[test]
+fn filter_overlapping_zero_query() {
+    let ranges = [Few::Two(1, 3), Few::One(3), Few::Zero];
+    assert!(filter_overlapping(&ranges, &Few::Zero).is_empty());
+    assert!(filter_overlapping::<i32>(&[], &Few::Two(1, 2)).is_empty());
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// ensure_nonempty_range
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn ensure_nonempty_range_two() {
+    assert_eq!(Few::Two(1, 2).ensure_nonempty_range(), Some(Few::Two(1, 2)));
+    assert_eq!(Few::Two(2, 1).ensure_nonempty_range(), Some(Few::Two(2, 1)));
+    assert_eq!(Few::Two(3, 3).ensure_nonempty_range(), None);
+}
+
+#[test]
+fn ensure_nonempty_range_one_and_zero() {
+    assert_eq!(Few::One(3).ensure_nonempty_range(), Some(Few::One(3)));
+    assert_eq!(Few::<i32>::Zero.ensure_nonempty_range(), None);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// cmp_by_range
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn cmp_by_range_bounds() {
+    use std::cmp::Ordering;
+    assert_eq!(Few::Two(1, 5).cmp_by_range(&Few::Two(2, 3)), Ordering::Less);
+    assert_eq!(Few::Two(1, 5).cmp_by_range(&Few::Two(1, 3)), Ordering::Greater);
+    assert_eq!(Few::Two(5, 1).cmp_by_range(&Few::Two(1, 5)), Ordering::Equal);
+    assert_eq!(Few::One(3).cmp_by_range(&Few::Two(3, 3)), Ordering::Equal);
+    assert_eq!(Few::Zero.cmp_by_range(&Few::One(i32::MIN)), Ordering::Less);
+}
+
+#[test]
+fn cmp_by_range_sort_mixed() {
+    let mut ranges = vec![
+        Few::Two(4, 2), Few::One(3), Few::Zero, Few::Two(1, 9),
+        Few::One(1), Few::Two(2, 3), Few::Zero,
+    ];
+    ranges.sort_by(Few::cmp_by_range);
+    assert_eq!(ranges, vec![
+        Few::Zero, Few::Zero, Few::One(1), Few::Two(1, 9), Few::Two(2, 3),
+        Few::Two(4, 2), Few::One(3),
+    ]);
+}
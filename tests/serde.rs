@@ -0,0 +1,100 @@
+////////////////////////////////////////////////////////////////////////////////
+// Few -- A generalization of `std::Option` allowing for up to two optional
+// values.
+////////////////////////////////////////////////////////////////////////////////
+// Copyright 2020 Skylor R. Schermer
+// This code is dual licenced using the MIT or Apache 2 license.
+// See licence-mit.md and licence-apache.md for details.
+////////////////////////////////////////////////////////////////////////////////
+//! Tests for serialization and deserialization of `Few` and `NonEmptyFew`.
+////////////////////////////////////////////////////////////////////////////////
+#![cfg(feature = "serde")]
+
+// Internal library imports.
+use few::Few;
+use few::NonEmptyFew;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Round trips
+////////////////////////////////////////////////////////////////////////////////
+#[test]
+fn json_round_trip() {
+    for few in [Few::Zero, Few::One(1), Few::Two(1, 2)] {
+        let text = serde_json::to_string(&few).unwrap();
+        assert_eq!(serde_json::from_str::<Few<i32>>(&text).unwrap(), few);
+    }
+    assert_eq!(serde_json::to_string(&Few::Two(1, 2)).unwrap(),
+        r#"{"Two":[1,2]}"#);
+}
+
+#[test]
+fn ron_round_trip() {
+    for few in [Few::Zero, Few::One(1), Few::Two(1, 2)] {
+        let text = ron::to_string(&few).unwrap();
+        assert_eq!(ron::from_str::<Few<i32>>(&text).unwrap(), few);
+    }
+    assert_eq!(ron::to_string(&Few::Two(1, 2)).unwrap(), "Two(1,2)");
+}
+
+#[test]
+fn nonempty_json_round_trip() {
+    for few in [NonEmptyFew::One(1), NonEmptyFew::Two(1, 2)] {
+        let text = serde_json::to_string(&few).unwrap();
+        assert_eq!(serde_json::from_str::<NonEmptyFew<i32>>(&text).unwrap(),
+            few);
+    }
+}
+
+#[test]
+fn nonempty_ron_round_trip() {
+    for few in [NonEmptyFew::One(1), NonEmptyFew::Two(1, 2)] {
+        let text = ron::to_string(&few).unwrap();
+        assert_eq!(ron::from_str::<NonEmptyFew<i32>>(&text).unwrap(), few);
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Malformed input
+////////////////////////////////////////////////////////////////////////////////
+fn json_error<T>(text: &str) -> String
+    where T: serde::de::DeserializeOwned + std::fmt::Debug,
+{
+    serde_json::from_str::<T>(text).unwrap_err().to_string()
+}
+
+#[test]
+fn two_too_short() {
+    assert_eq!(json_error::<Few<i32>>(r#"{"Two":[]}"#),
+        "invalid length 0, expected exactly two values at line 1 column 9");
+    assert_eq!(json_error::<Few<i32>>(r#"{"Two":[1]}"#),
+        "invalid length 1, expected exactly two values at line 1 column 10");
+}
+
+#[test]
+fn two_too_long() {
+    assert_eq!(json_error::<Few<i32>>(r#"{"Two":[1,2,3]}"#),
+        "invalid length 3, expected exactly two values at line 1 column 14");
+    assert_eq!(json_error::<NonEmptyFew<i32>>(r#"{"Two":[1,2,3,4]}"#),
+        "invalid length 4, expected exactly two values at line 1 column 16");
+}
+
+#[test]
+fn two_wrong_length_ron() {
+    let err = ron::from_str::<Few<i32>>("Two(1)").unwrap_err();
+    assert_eq!(err.to_string(),
+        "1:6: Expected exactly two values but found one element instead");
+    let err = ron::from_str::<Few<i32>>("Two(1,2,3)").unwrap_err();
+    assert_eq!(err.to_string(),
+        "1:10: Expected exactly two values but found 3 elements instead");
+}
+
+#[test]
+fn unknown_variant() {
+    assert_eq!(json_error::<Few<i32>>(r#"{"Three":[1,2,3]}"#),
+        "unknown variant `Three`, expected one of `Zero`, `One`, `Two` \
+        at line 1 column 8");
+    assert_eq!(json_error::<NonEmptyFew<i32>>(r#""Zero""#),
+        "unknown variant `Zero`, expected `One` or `Two` at line 1 column 6");
+}